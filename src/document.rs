@@ -0,0 +1,154 @@
+use crate::{ContentBuilder, DocumentClass, StringOrBuilder};
+
+type BodyFn<'a> = Box<dyn FnOnce(&mut ContentBuilder) + 'a>;
+
+/// A high-level builder for complete documents.
+///
+/// `Document` takes care of the document class, packages, the title block and the
+/// `document` environment, delegating the actual generation to `ContentBuilder`.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{Document, options};
+///
+/// let latex = Document::article()
+///     .package("amsmath", options![])
+///     .title("My First Document")
+///     .author("John Doe")
+///     .body(|b| {
+///         b.section("Introduction").add_literal("This is the introduction.\n");
+///     })
+///     .build();
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \documentclass{article}
+/// \usepackage{amsmath}
+/// \begin{document}
+/// \title{My First Document}
+/// \author{John Doe}
+/// \maketitle
+/// \section{Introduction}
+/// This is the introduction.
+/// \end{document}
+/// ```
+pub struct Document<'a> {
+    class: DocumentClass,
+    options: Vec<Box<dyn ToString>>,
+    packages: Vec<(String, Vec<Box<dyn ToString>>)>,
+    title: Option<String>,
+    author: Option<String>,
+    body: Option<BodyFn<'a>>,
+}
+
+impl<'a> Document<'a> {
+    /// Creates a new `Document` instance with the given document class.
+    ///
+    /// # Parameters
+    /// - `class`: The document class.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{Document, DocumentClass};
+    ///
+    /// let document = Document::new(DocumentClass::Report);
+    /// ```
+    pub fn new(class: DocumentClass) -> Self {
+        Document {
+            class,
+            options: Vec::new(),
+            packages: Vec::new(),
+            title: None,
+            author: None,
+            body: None,
+        }
+    }
+
+    /// Creates a new `Document` instance with the `article` document class.
+    pub fn article() -> Self {
+        Self::new(DocumentClass::Article)
+    }
+
+    /// Creates a new `Document` instance with the `report` document class.
+    pub fn report() -> Self {
+        Self::new(DocumentClass::Report)
+    }
+
+    /// Creates a new `Document` instance with the `book` document class.
+    pub fn book() -> Self {
+        Self::new(DocumentClass::Book)
+    }
+
+    /// Sets the options of the document class.
+    ///
+    /// # Parameters
+    /// - `options`: A list of options for the document class.
+    pub fn options(mut self, options: Vec<Box<dyn ToString>>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Adds a package to the document.
+    ///
+    /// # Parameters
+    /// - `package`: The name of the package.
+    /// - `options`: A list of options for the package.
+    pub fn package(mut self, package: &str, options: Vec<Box<dyn ToString>>) -> Self {
+        self.packages.push((package.to_string(), options));
+        self
+    }
+
+    /// Sets the title of the document, which is then typeset with `\maketitle`.
+    ///
+    /// The title is escaped like in `ContentBuilder::title`.
+    ///
+    /// # Parameters
+    /// - `title`: The title text.
+    pub fn title<S: StringOrBuilder>(mut self, title: S) -> Self {
+        self.title = Some(title.merge_escaped());
+        self
+    }
+
+    /// Sets the author of the document.
+    ///
+    /// # Parameters
+    /// - `author`: The author text.
+    pub fn author<S: StringOrBuilder>(mut self, author: S) -> Self {
+        self.author = Some(author.merge_str());
+        self
+    }
+
+    /// Sets the body of the document.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content of the document.
+    pub fn body<F: FnOnce(&mut ContentBuilder) + 'a>(mut self, f: F) -> Self {
+        self.body = Some(Box::new(f));
+        self
+    }
+
+    /// Builds and returns the generated LaTeX document as a string.
+    pub fn build(self) -> String {
+        let mut builder = ContentBuilder::new();
+        builder.set_document_class(self.class, self.options);
+        for (package, options) in self.packages {
+            builder.use_package(&package, options);
+        }
+        builder.begin_document();
+        if let Some(title) = &self.title {
+            builder.title_raw(title);
+        }
+        if let Some(author) = &self.author {
+            builder.author(author);
+        }
+        if self.title.is_some() {
+            builder.maketitle();
+        }
+        if let Some(body) = self.body {
+            body(&mut builder);
+        }
+        builder.end_document();
+        builder.build_document().to_string()
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+
+/// Represents errors reported when invalid input is passed to RustTeX.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, LatexError};
+///
+/// let mut builder = ContentBuilder::new();
+/// let result = builder.command("text sc", vec!["Hello"]);
+/// assert_eq!(result.err(), Some(LatexError::InvalidCommandName(String::from("text sc"))));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum LatexError {
+    /// A command name contains characters other than letters or `@`.
+    InvalidCommandName(String),
+    /// A command was declared or called with more than the 9 arguments LaTeX allows.
+    InvalidArgumentCount(u8),
+    /// A color specification does not match its color model.
+    InvalidColorSpec {
+        /// The color model the specification was checked against.
+        model: String,
+        /// The invalid specification.
+        spec: String,
+    },
+    /// A table row has a different number of cells than the header or first row.
+    ColumnCountMismatch {
+        /// The number of cells implied by the header or first row.
+        expected: usize,
+        /// The number of cells in the mismatching row.
+        found: usize,
+    },
+    /// An optional argument is omitted while a later optional argument is given, which
+    /// LaTeX's positional optional arguments cannot express.
+    SkippedOptionalArgument(String),
+    /// A color given by its specification rather than a name is used in a mix expression.
+    UnnamedColorMix(String),
+}
+
+impl fmt::Display for LatexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            LatexError::InvalidCommandName(name) => {
+                write!(f, "invalid command name `{}`: only letters and `@` are allowed", name)
+            }
+            LatexError::InvalidArgumentCount(count) => {
+                write!(f, "invalid argument count {}: commands take at most 9 arguments", count)
+            }
+            LatexError::InvalidColorSpec { model, spec } => {
+                write!(f, "invalid color specification `{}` for the `{}` color model", spec, model)
+            }
+            LatexError::ColumnCountMismatch { expected, found } => {
+                write!(f, "column count mismatch: expected {} cells, found {}", expected, found)
+            }
+            LatexError::SkippedOptionalArgument(name) => {
+                write!(f, "optional argument `{}` is omitted while a later one is given", name)
+            }
+            LatexError::UnnamedColorMix(spec) => {
+                write!(f, "color `{}` cannot be mixed: only named colors can be mixed", spec)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LatexError {}
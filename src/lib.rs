@@ -28,7 +28,7 @@
 //! ### Example 1: Basic Document
 //! 
 //! ```rust
-//! use rusttex::{ContentBuilder, DocumentClass};
+//! use rusttex::{ContentBuilder, DocumentClass, options};
 //! 
 //! let mut builder = ContentBuilder::new();
 //! builder.set_document_class(DocumentClass::Article, options![]);
@@ -64,15 +64,16 @@
 //! use rusttex::ContentBuilder;
 //! 
 //! let mut builder = ContentBuilder::new();
-//! builder.begin_document();
-//! builder.add_literal("Here is some ");
-//! builder.text_bold("bold text");
-//! builder.add_literal(", ");
-//! builder.text_italic("italic text");
-//! builder.add_literal(", and ");
-//! builder.text_underline("underlined text");
-//! builder.add_literal(".");
-//! builder.end_document();
+//! builder
+//!     .begin_document()
+//!     .add_literal("Here is some ")
+//!     .text_bold("bold text")
+//!     .add_literal(", ")
+//!     .text_italic("italic text")
+//!     .add_literal(", and ")
+//!     .text_underline("underlined text")
+//!     .add_literal(".")
+//!     .end_document();
 //! 
 //! println!("{}", builder.build_document());
 //! ```
@@ -174,17 +175,63 @@
 
 #![warn(missing_docs)]
 
+/// This module contains the high-level `Document` type of RustTeX.
+pub mod document;
+/// This module contains the error type used by RustTeX.
+pub mod error;
 /// This module contains the core models used by RustTeX.
 pub mod models;
 /// This module contains utility functions and macros for RustTeX.
 pub mod utils;
 
+pub use document::*;
+pub use error::*;
 pub use models::*;
 
+use std::collections::HashSet;
+
 /// This trait allows for exchanging Strings and String builders.
 pub trait StringOrBuilder {
     /// Merges the current instance into a `String`.
     fn merge_str(self) -> String;
+
+    /// Merges the current instance into a `String`, escaping LaTeX special characters.
+    ///
+    /// Builders produce LaTeX and are therefore never escaped.
+    fn merge_escaped(self) -> String
+    where
+        Self: Sized,
+    {
+        utils::escape_latex(&self.merge_str())
+    }
+
+    /// Merges the current instance into a `String` that is added to `builder`.
+    ///
+    /// Builders only return their body; anything they add to the preamble, such as packages,
+    /// colors or command definitions, is moved into the preamble of `builder`.
+    fn merge_into(self, builder: &mut ContentBuilder) -> String
+    where
+        Self: Sized,
+    {
+        let _ = builder;
+        self.merge_str()
+    }
+
+    /// Returns the color model required by the instance, if it is a color specification.
+    ///
+    /// Used by `ContentBuilder::text_color` to add the model of colors like `Color::Rgb`.
+    fn color_model(&self) -> Option<ColorModel> {
+        None
+    }
+
+    /// Like `merge_into`, escaping LaTeX special characters like `merge_escaped`.
+    fn merge_escaped_into(self, builder: &mut ContentBuilder) -> String
+    where
+        Self: Sized,
+    {
+        let _ = builder;
+        self.merge_escaped()
+    }
 }
 
 impl StringOrBuilder for &str {
@@ -193,17 +240,30 @@ impl StringOrBuilder for &str {
     }
 }
 
+impl StringOrBuilder for String {
+    fn merge_str(self) -> String {
+        self
+    }
+}
+
+impl StringOrBuilder for &String {
+    fn merge_str(self) -> String {
+        self.clone()
+    }
+}
+
 /// A builder for programmatically generating LaTeX documents.
 ///
 /// # Example
 /// ```rust
-/// use rusttex::{ContentBuilder, DocumentClass};
+/// use rusttex::{ContentBuilder, DocumentClass, options};
 /// 
 /// let mut builder = ContentBuilder::new();
-/// builder.set_document_class(DocumentClass::Article, options![]);
-/// builder.begin_document();
-/// builder.title("Example Document");
-/// builder.end_document();
+/// builder
+///     .set_document_class(DocumentClass::Article, options![])
+///     .begin_document()
+///     .title("Example Document")
+///     .end_document();
 /// 
 /// println!("{}", builder.build_document());
 /// ```
@@ -215,8 +275,22 @@ impl StringOrBuilder for &str {
 /// \title{Example Document}
 /// \end{document}
 /// ```
+#[derive(Clone)]
 pub struct ContentBuilder {
     content: String,
+    preamble_len: usize,
+    packages: HashSet<String>,
+    package_declarations: HashSet<String>,
+    colors: HashSet<String>,
+    in_document: bool,
+    document_class: Option<String>,
+    document_class_declaration: Option<String>,
+}
+
+impl Default for ContentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<F> StringOrBuilder for F
@@ -228,6 +302,20 @@ where
         self(&mut builder);
         builder.build_document().to_string()
     }
+
+    fn merge_escaped(self) -> String {
+        self.merge_str()
+    }
+
+    fn merge_into(self, builder: &mut ContentBuilder) -> String {
+        let mut nested = builder.nested();
+        self(&mut nested);
+        builder.absorb(nested)
+    }
+
+    fn merge_escaped_into(self, builder: &mut ContentBuilder) -> String {
+        self.merge_into(builder)
+    }
 }
 
 impl ContentBuilder {
@@ -235,18 +323,50 @@ impl ContentBuilder {
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let builder = ContentBuilder::new();
     /// ```
     pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new `ContentBuilder` instance with pre-allocated space for the document.
+    ///
+    /// Like `String::with_capacity`, this avoids repeated reallocations when generating
+    /// large documents.
+    ///
+    /// # Parameters
+    /// - `bytes`: The number of bytes to reserve for the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let builder = ContentBuilder::with_capacity(64 * 1024);
+    /// ```
+    pub fn with_capacity(bytes: usize) -> Self {
         ContentBuilder {
-            content: String::from(""),
+            content: String::with_capacity(bytes),
+            preamble_len: 0,
+            packages: HashSet::new(),
+            package_declarations: HashSet::new(),
+            colors: HashSet::new(),
+            in_document: false,
+            document_class: None,
+            document_class_declaration: None,
         }
     }
 
     /// Builds and returns the generated LaTeX document as a string slice.
     ///
+    /// The preamble (document class and packages) is always placed before the body,
+    /// regardless of the order in which the methods were called.
+    ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let builder = ContentBuilder::new();
     /// println!("{}", builder.build_document());
     /// ```
@@ -254,8 +374,120 @@ impl ContentBuilder {
         &self.content
     }
 
+    /// Clears the builder so it can be reused for a new document.
+    ///
+    /// The document is emptied while keeping its allocated capacity, and all
+    /// tracking state (used packages, document class, whether the document has begun) is reset.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// for name in ["first", "second"] {
+    ///     builder.reset();
+    ///     builder.begin_document().add_literal(name).end_document();
+    ///     println!("{}", builder.build_document());
+    /// }
+    /// ```
+    pub fn reset(&mut self) -> &mut Self {
+        self.content.clear();
+        self.preamble_len = 0;
+        self.packages.clear();
+        self.package_declarations.clear();
+        self.colors.clear();
+        self.in_document = false;
+        self.document_class = None;
+        self.document_class_declaration = None;
+        self
+    }
+
+    /// Creates an empty builder for nested content, sharing the packages and document class of `self`.
+    fn nested(&self) -> ContentBuilder {
+        ContentBuilder {
+            content: String::new(),
+            preamble_len: 0,
+            packages: self.packages.clone(),
+            package_declarations: self.package_declarations.clone(),
+            colors: self.colors.clone(),
+            in_document: self.in_document,
+            document_class: self.document_class.clone(),
+            document_class_declaration: None,
+        }
+    }
+
+    /// Moves the preamble of a nested builder into `self` and returns its body.
+    fn absorb(&mut self, nested: ContentBuilder) -> String {
+        let (preamble, body) = self.absorb_parts(nested);
+        self.push_preamble(&preamble);
+        body
+    }
+
+    /// Like `absorb`, returning the preamble of the nested builder instead of adding it.
+    ///
+    /// A document class set by the nested builder still replaces the one of `self`.
+    fn absorb_parts(&mut self, mut nested: ContentBuilder) -> (String, String) {
+        let body = nested.content.split_off(nested.preamble_len);
+        if let Some(declaration) = nested.document_class_declaration.take() {
+            nested.content.drain(..declaration.len());
+            self.replace_document_class(declaration);
+        }
+        self.packages.extend(nested.packages);
+        self.package_declarations.extend(nested.package_declarations);
+        self.colors.extend(nested.colors);
+        self.document_class = nested.document_class;
+        (nested.content, body)
+    }
+
+    /// Adds the declaration of a custom color to the preamble unless a color of that name
+    /// has already been defined.
+    pub(crate) fn ensure_color(&mut self, name: &str, declaration: &str) {
+        if self.colors.insert(name.to_string()) {
+            self.push_preamble(declaration);
+        }
+    }
+
+    /// Appends `text` to the preamble, which is kept in front of the body.
+    fn push_preamble(&mut self, text: &str) {
+        self.content.insert_str(self.preamble_len, text);
+        self.preamble_len += text.len();
+    }
+
+    /// Places `declaration` at the start of the preamble, replacing any previous document class.
+    fn replace_document_class(&mut self, declaration: String) {
+        let previous = self.document_class_declaration.as_ref().map_or(0, String::len);
+        self.content.replace_range(..previous, &declaration);
+        self.preamble_len = self.preamble_len - previous + declaration.len();
+        self.document_class_declaration = Some(declaration);
+    }
+
+    /// Returns an approximate count of the words in the body of the document.
+    ///
+    /// The count is a heuristic: command names, environment names, braces and comments are
+    /// ignored, and the remaining text is split on whitespace. Command arguments such as
+    /// labels or lengths are counted as words. The preamble is never counted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .begin_document()
+    ///     .section("Introduction")
+    ///     .add_literal("Some \\textbf{bold} text.\n")
+    ///     .end_document();
+    /// assert_eq!(builder.approx_word_count(), 4);
+    /// ```
+    pub fn approx_word_count(&self) -> usize {
+        utils::approx_word_count(&self.content[self.preamble_len..])
+    }
+
     /// Sets the document class for the LaTeX document.
     ///
+    /// The document class is always placed at the start of the preamble. Calling this method
+    /// again replaces the previously set document class.
+    ///
     /// # Parameters
     /// - `document_class`: The document class (e.g., `DocumentClass::Article`).
     /// - `options`: A list of options for the document class.
@@ -265,7 +497,9 @@ impl ContentBuilder {
     /// use rusttex::{ContentBuilder, DocumentClass, options};
     ///
     /// let mut builder = ContentBuilder::new();
+    /// builder.set_document_class(DocumentClass::Report, options![]);
     /// builder.set_document_class(DocumentClass::Article, options!["a4paper", "twocolumn"]);
+    /// assert_eq!(builder.build_document().matches("\\documentclass").count(), 1);
     /// ```
     ///
     /// **Generated LaTeX:**
@@ -276,655 +510,5277 @@ impl ContentBuilder {
         &mut self,
         document_class: DocumentClass,
         options: Vec<Box<dyn ToString>>,
-    ) {
-        if options.is_empty() {
-            self.content.push_str(&format!(
-                "\\documentclass{{{}}}\n",
-                document_class.to_string()
-            ));
+    ) -> &mut Self {
+        self.document_class = Some(document_class.to_string());
+        let declaration = if options.is_empty() {
+            format!("\\documentclass{{{}}}\n", document_class.to_string())
         } else {
             let options_str = options
                 .iter()
                 .map(|o| o.to_string())
                 .collect::<Vec<String>>()
                 .join(",");
-            self.content.push_str(&format!(
+            format!(
                 "\\documentclass[{}]{{{}}}\n",
                 options_str,
                 document_class.to_string()
-            ));
-        }
+            )
+        };
+        self.replace_document_class(declaration);
+        self
     }
 
     /// Adds a LaTeX package to the document.
     ///
+    /// A package that was already added with the exact same options is not emitted again.
+    /// The same package with different options is emitted once more.
+    ///
     /// # Parameters
     /// - `package`: The name of the package (e.g., `"amsmath"`).
     /// - `options`: A list of options for the package.
     ///
+    /// # Returns
+    /// `true` if the package declaration was newly added, `false` if it was a duplicate.
+    ///
     /// # Example
     /// ```rust
     /// use rusttex::{ContentBuilder, options};
     ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.use_package("amsmath", options!["fleqn"]);
+    /// assert!(builder.use_package("amsmath", options!["fleqn"]));
+    /// assert!(!builder.use_package("amsmath", options!["fleqn"]));
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
     /// \usepackage[fleqn]{amsmath}
     /// ```
-    pub fn use_package(&mut self, package: &str, options: Vec<Box<dyn ToString>>) {
-        if options.is_empty() {
-            self.content
-                .push_str(&format!("\\usepackage{{{}}}\n", package));
+    pub fn use_package(&mut self, package: &str, options: Vec<Box<dyn ToString>>) -> bool {
+        let declaration = if options.is_empty() {
+            format!("\\usepackage{{{}}}\n", package)
         } else {
             let options_str = options
                 .iter()
                 .map(|o| o.to_string())
                 .collect::<Vec<String>>()
                 .join(",");
-            self.content
-                .push_str(&format!("\\usepackage[{}]{{{}}}\n", options_str, package));
+            format!("\\usepackage[{}]{{{}}}\n", options_str, package)
+        };
+        if !self.package_declarations.insert(declaration.clone()) {
+            return false;
         }
+        self.packages.insert(package.to_string());
+        self.push_preamble(&declaration);
+        true
     }
 
-    /// Adds literal text to the document.
+    /// Adds a LaTeX package to the document unless it has already been added.
+    ///
+    /// Packages added from a nested builder, e.g. inside an environment closure, also end up
+    /// in the preamble of the enclosing document.
     ///
     /// # Parameters
-    /// - `text`: The text to add.
+    /// - `package`: The name of the package (e.g., `"hyperref"`).
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::{ContentBuilder, Environment, options};
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.add_literal("This is some text.");
+    /// builder.use_package("hyperref", options!["hidelinks"]);
+    /// builder.ensure_package("hyperref");
+    /// builder.env(Environment::Center, |b: &mut ContentBuilder| {
+    ///     b.ensure_package("amsmath");
+    /// });
+    /// assert!(builder.build_document().starts_with("\\usepackage[hidelinks]{hyperref}\n\\usepackage{amsmath}\n"));
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// This is some text.
+    /// \usepackage[hidelinks]{hyperref}
+    /// \usepackage{amsmath}
     /// ```
-    pub fn add_literal(&mut self, text: &str) {
-        self.content.push_str(text);
+    pub fn ensure_package(&mut self, package: &str) -> &mut Self {
+        if !self.packages.contains(package) {
+            self.use_package(package, vec![]);
+        }
+        self
     }
 
-    /// Begins the document environment.
+    /// Defines a new command in the preamble.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the command, without the leading backslash.
+    /// - `num_args`: The number of arguments the command takes, if any.
+    /// - `definition`: The body of the command, using `#1`, `#2`, ... for the arguments.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidCommandName` if `name` contains anything other than letters or `@`,
+    /// or `LatexError::InvalidArgumentCount` if `num_args` is greater than 9.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.begin_document();
+    /// builder.new_command("R", None, "\\mathbb{R}").unwrap();
+    /// builder.new_command("norm", Some(1), "\\left\\lVert#1\\right\\rVert").unwrap();
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \begin{document}
+    /// \newcommand{\R}{\mathbb{R}}
+    /// \newcommand{\norm}[1]{\left\lVert#1\right\rVert}
     /// ```
-    pub fn begin_document(&mut self) {
-        self.content.push_str("\\begin{document}\n");
+    pub fn new_command(&mut self, name: &str, num_args: Option<u8>, definition: &str) -> Result<&mut Self, LatexError> {
+        self.define_command("newcommand", name, num_args, definition)
     }
 
-    /// Ends the document environment.
+    /// Redefines an existing command in the preamble.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the command, without the leading backslash.
+    /// - `num_args`: The number of arguments the command takes, if any.
+    /// - `definition`: The body of the command, using `#1`, `#2`, ... for the arguments.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidCommandName` if `name` contains anything other than letters or `@`,
+    /// or `LatexError::InvalidArgumentCount` if `num_args` is greater than 9.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.end_document();
+    /// builder.renew_command("vec", Some(1), "\\mathbf{#1}").unwrap();
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \end{document}
+    /// \renewcommand{\vec}[1]{\mathbf{#1}}
     /// ```
-    pub fn end_document(&mut self) {
-        self.content.push_str("\\end{document}\n");
+    pub fn renew_command(&mut self, name: &str, num_args: Option<u8>, definition: &str) -> Result<&mut Self, LatexError> {
+        self.define_command("renewcommand", name, num_args, definition)
     }
 
-    /// Sets the title of the document.
+    /// Redefines an existing command in the preamble using the starred `\renewcommand*` form,
+    /// whose arguments may not span paragraphs.
     ///
     /// # Parameters
-    /// - `title`: The title text.
+    /// - `name`: The name of the command, without the leading backslash.
+    /// - `num_args`: The number of arguments the command takes, if any.
+    /// - `definition`: The body of the command, using `#1`, `#2`, ... for the arguments.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidCommandName` if `name` contains anything other than letters or `@`,
+    /// or `LatexError::InvalidArgumentCount` if `num_args` is greater than 9.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.title("My Document");
+    /// builder.renew_command_star("vec", Some(1), "\\mathbf{#1}").unwrap();
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \title{My Document}
+    /// \renewcommand*{\vec}[1]{\mathbf{#1}}
     /// ```
-    pub fn title<S: StringOrBuilder>(&mut self, title: S) {
-        self.content
-            .push_str(&format!("\\title{{{}}}\n", title.merge_str()));
+    pub fn renew_command_star(&mut self, name: &str, num_args: Option<u8>, definition: &str) -> Result<&mut Self, LatexError> {
+        self.define_command("renewcommand*", name, num_args, definition)
     }
 
-    /// Sets the author of the document.
+    /// Defines a command in the preamble only if it is not already defined.
     ///
     /// # Parameters
-    /// - `author`: The author text.
+    /// - `name`: The name of the command, without the leading backslash.
+    /// - `num_args`: The number of arguments the command takes, if any.
+    /// - `definition`: The body of the command, using `#1`, `#2`, ... for the arguments.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidCommandName` if `name` contains anything other than letters or `@`,
+    /// or `LatexError::InvalidArgumentCount` if `num_args` is greater than 9.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.author("John Doe");
+    /// builder.provide_command("R", None, "\\mathbb{R}").unwrap();
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \author{John Doe}
+    /// \providecommand{\R}{\mathbb{R}}
     /// ```
-    pub fn author<S: StringOrBuilder>(&mut self, author: S) {
-        self.content
-            .push_str(&format!("\\author{{{}}}\n", author.merge_str()));
+    pub fn provide_command(&mut self, name: &str, num_args: Option<u8>, definition: &str) -> Result<&mut Self, LatexError> {
+        self.define_command("providecommand", name, num_args, definition)
     }
 
-    /// Adds the `\maketitle` command to the document.
+    /// Makes `alias` a copy of the current meaning of `target` in the preamble.
+    ///
+    /// A leading backslash is added to both names unless already present.
+    ///
+    /// # Parameters
+    /// - `alias`: The name of the new command.
+    /// - `target`: The name of the existing command.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidCommandName` if `alias` contains anything other than letters or `@`.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.maketitle();
+    /// builder.let_command("origsection", "\\section").unwrap();
+    /// assert!(builder.let_command("orig section", "\\section").is_err());
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \maketitle
+    /// \let\origsection\section
     /// ```
-    pub fn maketitle(&mut self) {
-        self.content.push_str("\\maketitle\n");
+    pub fn let_command<S: StringOrBuilder, V: StringOrBuilder>(
+        &mut self,
+        alias: S,
+        target: V,
+    ) -> Result<&mut Self, LatexError> {
+        let alias = alias.merge_into(self);
+        let target = target.merge_into(self);
+        let alias = alias.trim_start_matches('\\');
+        if !utils::is_valid_command_name(alias) {
+            return Err(LatexError::InvalidCommandName(alias.to_string()));
+        }
+        self.push_preamble(&format!(
+            "\\let\\{}\\{}\n",
+            alias,
+            target.trim_start_matches('\\')
+        ));
+        Ok(self)
     }
 
-    /// Adds bold text to the document.
+    /// Adds a `\csname ... \endcsname` construct, which calls the command whose name is
+    /// given, allowing command names to be computed.
+    ///
+    /// The construct is added inline, without a trailing newline, so that arguments can follow.
     ///
     /// # Parameters
-    /// - `text`: The text to make bold.
+    /// - `name`: The name of the command, without the leading backslash.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.text_bold("Bold Text");
+    /// let chapter = 3;
+    /// builder.csname(format!("chapter{}summary", chapter)).add_literal("\n");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \textbf{Bold Text}
+    /// \csname chapter3summary\endcsname
     /// ```
-    pub fn text_bold<S: StringOrBuilder>(&mut self, text: S) {
+    pub fn csname<S: StringOrBuilder>(&mut self, name: S) -> &mut Self {
+        let name = name.merge_into(self);
         self.content
-            .push_str(&format!("\\textbf{{{}}}", text.merge_str()));
+            .push_str(&format!("\\csname {}\\endcsname", name));
+        self
     }
 
-    /// Adds italic text to the document.
+    /// Adds an `\ifthenelse` conditional, which typesets one of two branches depending on a condition.
+    ///
+    /// The `ifthen` package is added to the preamble if it is not already present.
     ///
     /// # Parameters
-    /// - `text`: The text to italicize.
+    /// - `condition`: The condition, e.g. `\value{chapter} > 2` or `\boolean{draft}`.
+    /// - `then_branch`: The content used when the condition holds.
+    /// - `else_branch`: The content used otherwise.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.text_italic("Italic Text");
+    /// builder.if_then_else("\\value{page} > 1", "Continued", "");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \textit{Italic Text}
+    /// \usepackage{ifthen}
+    /// \ifthenelse{\value{page} > 1}{Continued}{}
     /// ```
-    pub fn text_italic<S: StringOrBuilder>(&mut self, text: S) {
-        self.content
-            .push_str(&format!("\\textit{{{}}}", text.merge_str()));
+    pub fn if_then_else<S: StringOrBuilder, V: StringOrBuilder, T: StringOrBuilder>(
+        &mut self,
+        condition: S,
+        then_branch: V,
+        else_branch: T,
+    ) -> &mut Self {
+        let condition = condition.merge_into(self);
+        let then_branch = then_branch.merge_into(self);
+        let else_branch = else_branch.merge_into(self);
+        self.ensure_package("ifthen");
+        self.content.push_str(&format!(
+            "\\ifthenelse{{{}}}{{{}}}{{{}}}\n",
+            condition,
+            then_branch,
+            else_branch
+        ));
+        self
     }
 
-    /// Adds underlined text to the document.
+    /// Adds a `\foreach` loop, which repeats its body for each item of a list at compile time.
+    ///
+    /// The `pgffor` package is added to the preamble if it is not already present.
+    /// A leading backslash is added to the loop variable unless already present.
     ///
     /// # Parameters
-    /// - `text`: The text to underline.
+    /// - `var`: The name of the loop variable, e.g. `x`.
+    /// - `list`: The comma-separated list of items, e.g. `1,...,5`.
+    /// - `body`: The content repeated for each item, using the loop variable.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.text_underline("Underlined Text");
+    /// builder.foreach("x", "1,...,5", "Item \\x. ");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \underline{Underlined Text}
+    /// \usepackage{pgffor}
+    /// \foreach \x in {1,...,5}{Item \x. }
     /// ```
-    pub fn text_underline<S: StringOrBuilder>(&mut self, text: S) {
-        self.content
-            .push_str(&format!("\\underline{{{}}}", text.merge_str()));
+    pub fn foreach<S: StringOrBuilder, V: StringOrBuilder, B: StringOrBuilder>(
+        &mut self,
+        var: S,
+        list: V,
+        body: B,
+    ) -> &mut Self {
+        let var = var.merge_into(self);
+        let list = list.merge_into(self);
+        let body = body.merge_into(self);
+        self.ensure_package("pgffor");
+        self.content.push_str(&format!(
+            "\\foreach \\{} in {{{}}}{{{}}}\n",
+            var.trim_start_matches('\\'),
+            list,
+            body
+        ));
+        self
     }
 
-    /// Adds a new line to the document.
+    /// Declares a new box register, which can then be filled with `save_box` and typeset with `use_box`.
+    ///
+    /// A leading backslash is added to the name unless already present.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the box.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.new_line();
+    /// builder
+    ///     .new_save_box("logobox")
+    ///     .save_box("logobox", "\\textbf{ACME}")
+    ///     .use_box("logobox");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \\
+    /// \newsavebox{\logobox}
+    /// \savebox{\logobox}{\textbf{ACME}}
+    /// \usebox{\logobox}
     /// ```
-    pub fn new_line(&mut self) {
-        self.content.push_str("\\\\\n");
+    pub fn new_save_box<S: StringOrBuilder>(&mut self, name: S) -> &mut Self {
+        let name = name.merge_into(self);
+        self.content.push_str(&format!(
+            "\\newsavebox{{\\{}}}\n",
+            name.trim_start_matches('\\')
+        ));
+        self
     }
 
-    /// Adds a label to the document.
+    /// Stores content in a box register declared with `new_save_box`.
+    ///
+    /// A leading backslash is added to the name unless already present.
     ///
     /// # Parameters
-    /// - `label`: The label text.
+    /// - `name`: The name of the box.
+    /// - `content`: The content to store in the box.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.label("sec:intro");
+    /// builder.save_box("logobox", "\\textbf{ACME}");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \label{sec:intro}
+    /// \savebox{\logobox}{\textbf{ACME}}
     /// ```
-    pub fn label<S: StringOrBuilder>(&mut self, label: S) {
-        self.content
-            .push_str(&format!("\\label{{{}}}\n", label.merge_str()));
+    pub fn save_box<S: StringOrBuilder, V: StringOrBuilder>(&mut self, name: S, content: V) -> &mut Self {
+        let name = name.merge_into(self);
+        let content = content.merge_into(self);
+        self.content.push_str(&format!(
+            "\\savebox{{\\{}}}{{{}}}\n",
+            name.trim_start_matches('\\'),
+            content
+        ));
+        self
     }
 
-    /// Adds a section to the document.
+    /// Typesets the content stored in a box register.
+    ///
+    /// A leading backslash is added to the name unless already present.
     ///
     /// # Parameters
-    /// - `title`: The title of the section.
+    /// - `name`: The name of the box.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.section("Introduction");
+    /// builder.add_literal("Made by ").use_box("logobox");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \section{Introduction}
+    /// Made by \usebox{\logobox}
     /// ```
-    pub fn section<S: StringOrBuilder>(&mut self, title: S) {
+    pub fn use_box<S: StringOrBuilder>(&mut self, name: S) -> &mut Self {
+        let name = name.merge_into(self);
         self.content
-            .push_str(&format!("\\section{{{}}}\n", title.merge_str()));
+            .push_str(&format!("\\usebox{{\\{}}}", name.trim_start_matches('\\')));
+        self
     }
 
-    /// Adds a subsection to the document.
+    /// Declares a new length register, which can then be set with `set_to_width` and its twins.
+    ///
+    /// A leading backslash is added to the name unless already present.
     ///
     /// # Parameters
-    /// - `title`: The title of the subsection.
+    /// - `name`: The name of the length.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.subsection("Background");
+    /// builder
+    ///     .new_length("labelwidth")
+    ///     .set_to_width("labelwidth", "\\textbf{Description:}");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \subsection{Background}
+    /// \newlength{\labelwidth}
+    /// \settowidth{\labelwidth}{\textbf{Description:}}
     /// ```
-    pub fn subsection<S: StringOrBuilder>(&mut self, title: S) {
-        self.content
-            .push_str(&format!("\\subsection{{{}}}\n", title.merge_str()));
+    pub fn new_length<S: StringOrBuilder>(&mut self, name: S) -> &mut Self {
+        let name = name.merge_into(self);
+        self.content.push_str(&format!(
+            "\\newlength{{\\{}}}\n",
+            name.trim_start_matches('\\')
+        ));
+        self
     }
 
-    /// Adds a subsubsection to the document.
+    /// Sets a length register to the natural width of the given content.
+    ///
+    /// A leading backslash is added to the length name unless already present.
     ///
     /// # Parameters
-    /// - `title`: The title of the subsubsection.
+    /// - `length`: The name of the length, declared with `new_length`.
+    /// - `content`: The content to measure.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.subsubsection("Details");
+    /// builder.set_to_width("labelwidth", "\\textbf{Description:}");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \subsubsection{Details}
+    /// \settowidth{\labelwidth}{\textbf{Description:}}
     /// ```
-    pub fn subsubsection<S: StringOrBuilder>(&mut self, title: S) {
-        self.content
-            .push_str(&format!("\\subsubsection{{{}}}\n", title.merge_str()));
+    pub fn set_to_width<S: StringOrBuilder, V: StringOrBuilder>(&mut self, length: S, content: V) -> &mut Self {
+        self.measure("settowidth", length, content)
     }
 
-    /// Adds a paragraph to the document.
+    /// Sets a length register to the height of the given content above the baseline.
+    ///
+    /// A leading backslash is added to the length name unless already present.
     ///
     /// # Parameters
-    /// - `text`: The text of the paragraph.
+    /// - `length`: The name of the length, declared with `new_length`.
+    /// - `content`: The content to measure.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.paragraph("This is a paragraph.");
+    /// builder.set_to_height("capheight", "A");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \paragraph{This is a paragraph.}
+    /// \settoheight{\capheight}{A}
     /// ```
-    pub fn paragraph<S: StringOrBuilder>(&mut self, text: S) {
-        self.content
-            .push_str(&format!("\\paragraph{{{}}}\n", text.merge_str()));
+    pub fn set_to_height<S: StringOrBuilder, V: StringOrBuilder>(&mut self, length: S, content: V) -> &mut Self {
+        self.measure("settoheight", length, content)
     }
 
-    /// Adds a subparagraph to the document.
+    /// Sets a length register to the depth of the given content below the baseline.
+    ///
+    /// A leading backslash is added to the length name unless already present.
     ///
     /// # Parameters
-    /// - `text`: The text of the subparagraph.
+    /// - `length`: The name of the length, declared with `new_length`.
+    /// - `content`: The content to measure.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.subparagraph("This is a subparagraph.");
+    /// builder.set_to_depth("descdepth", "g");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \subparagraph{This is a subparagraph.}
+    /// \settodepth{\descdepth}{g}
     /// ```
-    pub fn subparagraph<S: StringOrBuilder>(&mut self, text: S) {
-        self.content
-            .push_str(&format!("\\subparagraph{{{}}}\n", text.merge_str()));
+    pub fn set_to_depth<S: StringOrBuilder, V: StringOrBuilder>(&mut self, length: S, content: V) -> &mut Self {
+        self.measure("settodepth", length, content)
     }
 
-    /// Adds a footnote to the document.
+    fn measure<S: StringOrBuilder, V: StringOrBuilder>(&mut self, command: &str, length: S, content: V) -> &mut Self {
+        let length = length.merge_into(self);
+        let content = content.merge_into(self);
+        self.content.push_str(&format!(
+            "\\{}{{\\{}}}{{{}}}\n",
+            command,
+            length.trim_start_matches('\\'),
+            content
+        ));
+        self
+    }
+
+    /// Declares a new theorem-like environment in the preamble.
     ///
     /// # Parameters
-    /// - `text`: The text of the footnote.
+    /// - `env_name`: The name of the environment, e.g. `theorem`.
+    /// - `display_name`: The name printed in the document, e.g. `Theorem`.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::{ContentBuilder, Environment, TheoremParams};
+    ///
+    /// let params = TheoremParams::new(Some("Pythagoras"));
     /// let mut builder = ContentBuilder::new();
-    /// builder.footnote("This is a footnote.");
+    /// builder
+    ///     .new_theorem("theorem", "Theorem")
+    ///     .env(Environment::Theorem(&params), "$a^2 + b^2 = c^2$");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \footnote{This is a footnote.}
+    /// \newtheorem{theorem}{Theorem}
+    /// \begin{theorem}[Pythagoras]
+    /// $a^2 + b^2 = c^2$
+    /// \end{theorem}
     /// ```
-    pub fn footnote<S: StringOrBuilder>(&mut self, text: S) {
-        self.content
-            .push_str(&format!("\\footnote{{{}}}", text.merge_str()));
+    pub fn new_theorem(&mut self, env_name: &str, display_name: &str) -> &mut Self {
+        self.push_preamble(&format!(
+            "\\newtheorem{{{}}}{{{}}}\n",
+            env_name, display_name
+        ));
+        self
     }
 
-    /// Adds a citation to the document.
+    /// Sets the Beamer presentation theme in the preamble.
+    ///
+    /// Requires the `beamer` document class.
     ///
     /// # Parameters
-    /// - `citation`: The citation key.
-    /// - `subcitation`: An optional subcitation.
+    /// - `theme`: The name of the presentation theme.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::{ContentBuilder, BeamerTheme};
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.cite("doe2020", Some("p. 42"));
+    /// builder.use_theme(BeamerTheme::Madrid);
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \cite[p. 42]{doe2020}
+    /// \usetheme{Madrid}
     /// ```
-    pub fn cite<S: StringOrBuilder, V: StringOrBuilder>(&mut self, citation: S, subcitation: Option<V>) {
-        let subcitation_str = match subcitation {
-            Some(sub) => format!("[{}]", sub.merge_str()),
-            None => String::new(),
-        };
-        self.content
-            .push_str(&format!("\\cite{}{{{}}}", subcitation_str, citation.merge_str()));
+    pub fn use_theme<S: StringOrBuilder>(&mut self, theme: S) -> &mut Self {
+        let theme = theme.merge_into(self);
+        self.push_preamble(&format!("\\usetheme{{{}}}\n", theme));
+        self
     }
 
-    /// Adds a reference to a label in the document.
+    /// Sets the Beamer color theme in the preamble.
+    ///
+    /// Requires the `beamer` document class.
     ///
     /// # Parameters
-    /// - `label`: The label to reference.
+    /// - `theme`: The name of the color theme.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.ref_label("sec:intro");
+    /// builder.use_color_theme("dolphin");
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \ref{sec:intro}
+    /// \usecolortheme{dolphin}
     /// ```
-    pub fn ref_label<S: StringOrBuilder>(&mut self, label: S) {
-        self.content
-            .push_str(&format!("\\ref{{{}}}", label.merge_str()));
+    pub fn use_color_theme<S: StringOrBuilder>(&mut self, theme: S) -> &mut Self {
+        let theme = theme.merge_into(self);
+        self.push_preamble(&format!("\\usecolortheme{{{}}}\n", theme));
+        self
     }
 
-    /// Adds colored text to the document.
+    /// Sets the Beamer font theme in the preamble.
+    ///
+    /// Requires the `beamer` document class.
     ///
     /// # Parameters
-    /// - `text`: The text to color.
-    /// - `color`: The color to apply.
+    /// - `theme`: The name of the font theme.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.use_font_theme("serif");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \usefonttheme{serif}
+    /// ```
+    pub fn use_font_theme<S: StringOrBuilder>(&mut self, theme: S) -> &mut Self {
+        let theme = theme.merge_into(self);
+        self.push_preamble(&format!("\\usefonttheme{{{}}}\n", theme));
+        self
+    }
+
+    /// Sets the Beamer inner theme in the preamble.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `theme`: The name of the inner theme.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.use_inner_theme("rounded");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \useinnertheme{rounded}
+    /// ```
+    pub fn use_inner_theme<S: StringOrBuilder>(&mut self, theme: S) -> &mut Self {
+        let theme = theme.merge_into(self);
+        self.push_preamble(&format!("\\useinnertheme{{{}}}\n", theme));
+        self
+    }
+
+    /// Sets the Beamer outer theme in the preamble.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `theme`: The name of the outer theme.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.use_outer_theme("infolines");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \useoutertheme{infolines}
+    /// ```
+    pub fn use_outer_theme<S: StringOrBuilder>(&mut self, theme: S) -> &mut Self {
+        let theme = theme.merge_into(self);
+        self.push_preamble(&format!("\\useoutertheme{{{}}}\n", theme));
+        self
+    }
+
+    /// Sets a Beamer template in the preamble.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `element`: The Beamer element to customize, e.g. `footline`.
+    /// - `definition`: The definition of the template.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_beamer_template("footline", "[frame number]");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \setbeamertemplate{footline}{[frame number]}
+    /// ```
+    pub fn set_beamer_template<S: StringOrBuilder, V: StringOrBuilder>(&mut self, element: S, definition: V) -> &mut Self {
+        let element = element.merge_into(self);
+        let definition = definition.merge_into(self);
+        self.push_preamble(&format!(
+            "\\setbeamertemplate{{{}}}{{{}}}\n",
+            element,
+            definition
+        ));
+        self
+    }
+
+    /// Hides the Beamer navigation symbols.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.hide_navigation_symbols();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \setbeamertemplate{navigation symbols}{}
+    /// ```
+    pub fn hide_navigation_symbols(&mut self) -> &mut Self {
+        self.set_beamer_template("navigation symbols", "")
+    }
+
+    fn define_command(
+        &mut self,
+        definer: &str,
+        name: &str,
+        num_args: Option<u8>,
+        definition: &str,
+    ) -> Result<&mut Self, LatexError> {
+        if !utils::is_valid_command_name(name) {
+            return Err(LatexError::InvalidCommandName(name.to_string()));
+        }
+        let num_args_str = match num_args {
+            Some(n) if n > 9 => return Err(LatexError::InvalidArgumentCount(n)),
+            Some(n) => format!("[{}]", n),
+            None => String::new(),
+        };
+        self.push_preamble(&format!(
+            "\\{}{{\\{}}}{}{{{}}}\n",
+            definer, name, num_args_str, definition
+        ));
+        Ok(self)
+    }
+
+    /// Adds literal text to the document.
+    ///
+    /// # Parameters
+    /// - `text`: The text to add.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.add_literal("This is some text.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// This is some text.
+    /// ```
+    pub fn add_literal(&mut self, text: &str) -> &mut Self {
+        self.content.push_str(text);
+        self
+    }
+
+    /// Adds raw lines to the preamble.
+    ///
+    /// Unlike `add_literal`, which targets the body, the lines always end up before
+    /// `\begin{document}`, regardless of the order in which the methods were called.
+    /// The text is written as-is, without escaping; a trailing newline is added if missing.
+    ///
+    /// # Parameters
+    /// - `raw`: The raw preamble lines.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.begin_document().add_preamble("\\pagestyle{plain}").end_document();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \pagestyle{plain}
+    /// \begin{document}
+    /// \end{document}
+    /// ```
+    pub fn add_preamble(&mut self, raw: &str) -> &mut Self {
+        self.push_preamble(raw);
+        if !raw.ends_with('\n') {
+            self.push_preamble("\n");
+        }
+        self
+    }
+
+    /// Wraps the content added by the closure in `\makeatletter` and `\makeatother`,
+    /// so that internal commands containing `@` can be used or patched.
+    ///
+    /// Since most such patches end up in the preamble, the wrapping is applied to both the
+    /// preamble and the body, wherever the closure added content.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content that uses `@` commands.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.make_at_letter_fn(|b| {
+    ///     b.add_preamble("\\renewcommand{\\@seccntformat}[1]{}");
+    /// });
+    /// ```
+    ///
+    /// The document class may be set inside the closure; it still heads the document:
+    /// ```rust
+    /// use rusttex::{ContentBuilder, DocumentClass, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.make_at_letter_fn(|b| {
+    ///     b.set_document_class(DocumentClass::Article, options![]);
+    ///     b.add_preamble("\\renewcommand{\\@seccntformat}[1]{}");
+    /// });
+    /// assert_eq!(
+    ///     builder.build_document(),
+    ///     "\\documentclass{article}\n\\makeatletter\n\\renewcommand{\\@seccntformat}[1]{}\n\\makeatother\n"
+    /// );
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \makeatletter
+    /// \renewcommand{\@seccntformat}[1]{}
+    /// \makeatother
+    /// ```
+    pub fn make_at_letter_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        let mut nested = self.nested();
+        f(&mut nested);
+        let (preamble, body) = self.absorb_parts(nested);
+        if !preamble.is_empty() {
+            self.push_preamble(&format!("\\makeatletter\n{}\\makeatother\n", preamble));
+        }
+        if !body.is_empty() {
+            self.content
+                .push_str(&format!("\\makeatletter\n{}\\makeatother\n", body));
+        }
+        self
+    }
+
+    /// Adds an arbitrary command with mandatory arguments to the document.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the command, without the leading backslash.
+    /// - `args`: The mandatory arguments of the command.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidCommandName` if `name` contains anything other than letters or `@`,
+    /// or `LatexError::InvalidArgumentCount` if more than 9 arguments are given.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.command("textsc", vec!["Hello"]).unwrap();
+    /// assert!(builder.command("foo", vec!["x"; 10]).is_err());
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \textsc{Hello}
+    /// ```
+    pub fn command<S: StringOrBuilder>(&mut self, name: &str, args: Vec<S>) -> Result<&mut Self, LatexError> {
+        self.command_opt::<S, &str>(name, Vec::new(), args)
+    }
+
+    /// Adds an arbitrary command with optional and mandatory arguments to the document.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the command, without the leading backslash.
+    /// - `opt_args`: The optional arguments of the command.
+    /// - `args`: The mandatory arguments of the command.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidCommandName` if `name` contains anything other than letters or `@`,
+    /// or `LatexError::InvalidArgumentCount` if more than 9 arguments are given in total.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.command_opt("includegraphics", vec!["width=5cm"], vec!["plot.png"]).unwrap();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \includegraphics[width=5cm]{plot.png}
+    /// ```
+    pub fn command_opt<S: StringOrBuilder, V: StringOrBuilder>(
+        &mut self,
+        name: &str,
+        opt_args: Vec<V>,
+        args: Vec<S>,
+    ) -> Result<&mut Self, LatexError> {
+        if !utils::is_valid_command_name(name) {
+            return Err(LatexError::InvalidCommandName(name.to_string()));
+        }
+        let count = opt_args.len() + args.len();
+        if count > 9 {
+            return Err(LatexError::InvalidArgumentCount(u8::try_from(count).unwrap_or(u8::MAX)));
+        }
+        let opt_args_str = opt_args
+            .into_iter()
+            .map(|a| format!("[{}]", a.merge_into(self)))
+            .collect::<String>();
+        let args_str = args
+            .into_iter()
+            .map(|a| format!("{{{}}}", a.merge_into(self)))
+            .collect::<String>();
+        self.content
+            .push_str(&format!("\\{}{}{}", name, opt_args_str, args_str));
+        Ok(self)
+    }
+
+    /// Begins the document environment.
+    ///
+    /// # Panics
+    /// Panics if the document environment has already been begun.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.begin_document();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{document}
+    /// ```
+    pub fn begin_document(&mut self) -> &mut Self {
+        assert!(!self.in_document, "`begin_document` called while the document is already open");
+        self.in_document = true;
+        self.content.push_str("\\begin{document}\n");
+        self
+    }
+
+    /// Ends the document environment.
+    ///
+    /// # Panics
+    /// Panics if the document environment has not been begun.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.begin_document().end_document();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{document}
+    /// \end{document}
+    /// ```
+    pub fn end_document(&mut self) -> &mut Self {
+        assert!(self.in_document, "`end_document` called without a matching `begin_document`");
+        self.in_document = false;
+        self.content.push_str("\\end{document}\n");
+        self
+    }
+
+    /// Sets the title of the document.
+    ///
+    /// The title is escaped with `merge_escaped`, so special characters such as `&` or `%`
+    /// are typeset literally. Use `title_raw` to embed LaTeX commands.
+    ///
+    /// # Parameters
+    /// - `title`: The title text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.title("Research & Development");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \title{Research \& Development}
+    /// ```
+    pub fn title<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        let title = title.merge_escaped_into(self);
+        self.content
+            .push_str(&format!("\\title{{{}}}\n", title));
+        self
+    }
+
+    /// Sets the title of the document without escaping it.
+    ///
+    /// # Parameters
+    /// - `title`: The title, as LaTeX code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.title_raw("Typesetting with \\LaTeX");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \title{Typesetting with \LaTeX}
+    /// ```
+    pub fn title_raw<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        let title = title.merge_into(self);
+        self.content
+            .push_str(&format!("\\title{{{}}}\n", title));
+        self
+    }
+
+    /// Sets the author of the document.
+    ///
+    /// # Parameters
+    /// - `author`: The author text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.author("John Doe");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \author{John Doe}
+    /// ```
+    pub fn author<S: StringOrBuilder>(&mut self, author: S) -> &mut Self {
+        let author = author.merge_into(self);
+        self.content
+            .push_str(&format!("\\author{{{}}}\n", author));
+        self
+    }
+
+    /// Sets multiple authors of the document, joined with `\and`.
+    ///
+    /// Nothing is added if `authors` is empty.
+    ///
+    /// # Parameters
+    /// - `authors`: The author texts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.authors(["John Doe", "Jane Roe"]);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \author{John Doe \and Jane Roe}
+    /// ```
+    pub fn authors<I, S>(&mut self, authors: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: StringOrBuilder,
+    {
+        let authors_str = authors
+            .into_iter()
+            .map(|author| author.merge_into(self))
+            .collect::<Vec<String>>();
+        if authors_str.is_empty() {
+            return self;
+        }
+        self.author(authors_str.join(" \\and "))
+    }
+
+    /// Sets the date of the document.
+    ///
+    /// # Parameters
+    /// - `date`: The date text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.date("1 January 2025");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \date{1 January 2025}
+    /// ```
+    pub fn date<S: StringOrBuilder>(&mut self, date: S) -> &mut Self {
+        let date = date.merge_into(self);
+        self.content
+            .push_str(&format!("\\date{{{}}}\n", date));
+        self
+    }
+
+    /// Sets the date of the document to the day of compilation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.date_today();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \date{\today}
+    /// ```
+    pub fn date_today(&mut self) -> &mut Self {
+        self.date("\\today")
+    }
+
+    /// Sets an empty date, hiding the date from the title.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.date_empty();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \date{}
+    /// ```
+    pub fn date_empty(&mut self) -> &mut Self {
+        self.date("")
+    }
+
+    /// Adds a dedication page with the given text, centered horizontally and vertically.
+    ///
+    /// # Parameters
+    /// - `text`: The dedication text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.dedication("To my parents");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \clearpage
+    /// \vspace*{\fill}
+    /// \begin{center}
+    /// To my parents
+    /// \end{center}
+    /// \vspace*{\fill}
+    /// \clearpage
+    /// ```
+    pub fn dedication<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.dedication_fn(|b| {
+            b.add_literal(&text);
+        })
+    }
+
+    /// Adds a dedication page, centered horizontally and vertically.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content of the dedication.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.dedication_fn(|b| {
+    ///     b.text_italic("To my parents");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \clearpage
+    /// \vspace*{\fill}
+    /// \begin{center}
+    /// \textit{To my parents}
+    /// \end{center}
+    /// \vspace*{\fill}
+    /// \clearpage
+    /// ```
+    pub fn dedication_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.content
+            .push_str("\\clearpage\n\\vspace*{\\fill}\n\\begin{center}\n");
+        f(self);
+        self.content
+            .push_str("\n\\end{center}\n\\vspace*{\\fill}\n\\clearpage\n");
+        self
+    }
+
+    /// Adds a `quotation` environment followed by a flush-right, em-dash prefixed attribution.
+    ///
+    /// # Parameters
+    /// - `attribution`: The source of the quotation.
+    /// - `f`: A closure adding the quoted content.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.quotation_attributed_fn("Albert Einstein", |b| {
+    ///     b.add_literal("Imagination is more important than knowledge.");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{quotation}
+    /// Imagination is more important than knowledge.
+    /// \par\hfill --- Albert Einstein
+    /// \end{quotation}
+    /// ```
+    pub fn quotation_attributed_fn<F: FnOnce(&mut ContentBuilder), S: StringOrBuilder>(
+        &mut self,
+        attribution: S,
+        f: F,
+    ) -> &mut Self {
+        let attribution = attribution.merge_into(self);
+        self.content.push_str("\\begin{quotation}\n");
+        f(self);
+        self.content.push_str(&format!(
+            "\n\\par\\hfill --- {}\n\\end{{quotation}}\n",
+            attribution
+        ));
+        self
+    }
+
+    /// Adds a group whose paragraphs are left-aligned, ragged right, using the `\raggedright` declaration.
+    ///
+    /// Unlike the corresponding environment, no extra vertical space is added. The group
+    /// ends with `\par` so the alignment also applies to the last line.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content of the group.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.ragged_right_fn(|b| {
+    ///     b.add_literal("Left-aligned text.");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// {\raggedright
+    /// Left-aligned text.\par}
+    /// ```
+    pub fn ragged_right_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.declaration_group("raggedright", f)
+    }
+
+    /// Adds a group whose paragraphs are right-aligned, ragged left, using the `\raggedleft` declaration.
+    ///
+    /// Unlike the corresponding environment, no extra vertical space is added. The group
+    /// ends with `\par` so the alignment also applies to the last line.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content of the group.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.ragged_left_fn(|b| {
+    ///     b.add_literal("Right-aligned text.");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// {\raggedleft
+    /// Right-aligned text.\par}
+    /// ```
+    pub fn ragged_left_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.declaration_group("raggedleft", f)
+    }
+
+    /// Adds a group whose paragraphs are centered, using the `\centering` declaration.
+    ///
+    /// Unlike the corresponding environment, no extra vertical space is added. The group
+    /// ends with `\par` so the alignment also applies to the last line.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content of the group.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.centered_fn(|b| {
+    ///     b.add_literal("Centered text.");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// {\centering
+    /// Centered text.\par}
+    /// ```
+    pub fn centered_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.declaration_group("centering", f)
+    }
+
+    fn declaration_group<F: FnOnce(&mut ContentBuilder)>(&mut self, declaration: &str, f: F) -> &mut Self {
+        self.content.push_str(&format!("{{\\{}\n", declaration));
+        f(self);
+        self.content.push_str("\\par}\n");
+        self
+    }
+
+    /// Adds a paragraph with a hanging indent, where all lines but the first are indented.
+    ///
+    /// The paragraph is ended with `\par`, so the indent only affects this paragraph.
+    ///
+    /// # Parameters
+    /// - `indent`: The width of the hanging indent.
+    /// - `text`: The text of the paragraph.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, Length};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.hanging_paragraph(Length::em(2.0), "Knuth, D. The TeXbook. Addison-Wesley, 1984.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \hangindent=2em \hangafter=1 Knuth, D. The TeXbook. Addison-Wesley, 1984.\par
+    /// ```
+    pub fn hanging_paragraph<S: StringOrBuilder, V: StringOrBuilder>(&mut self, indent: S, text: V) -> &mut Self {
+        let indent = indent.merge_into(self);
+        let text = text.merge_into(self);
+        self.content.push_str(&format!(
+            "\\hangindent={} \\hangafter=1 {}\\par\n",
+            indent,
+            text
+        ));
+        self
+    }
+
+    /// Adds the `\maketitle` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.maketitle();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \maketitle
+    /// ```
+    pub fn maketitle(&mut self) -> &mut Self {
+        self.content.push_str("\\maketitle\n");
+        self
+    }
+
+    /// Sets the institute shown on a Beamer title page.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `inst`: The institute text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.institute("University of Warsaw");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \institute{University of Warsaw}
+    /// ```
+    pub fn institute<S: StringOrBuilder>(&mut self, inst: S) -> &mut Self {
+        let inst = inst.merge_into(self);
+        self.content
+            .push_str(&format!("\\institute{{{}}}\n", inst));
+        self
+    }
+
+    /// Sets the graphic shown on a Beamer title page.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `graphic`: The graphic content, usually an `\includegraphics` command.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.title_graphic("\\includegraphics[width=2cm]{logo.png}");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \titlegraphic{\includegraphics[width=2cm]{logo.png}}
+    /// ```
+    pub fn title_graphic<S: StringOrBuilder>(&mut self, graphic: S) -> &mut Self {
+        let graphic = graphic.merge_into(self);
+        self.content
+            .push_str(&format!("\\titlegraphic{{{}}}\n", graphic));
+        self
+    }
+
+    /// Sets the logo shown on every Beamer frame.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `logo`: The logo content, usually an `\includegraphics` command.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.logo("\\includegraphics[height=1cm]{logo.png}");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \logo{\includegraphics[height=1cm]{logo.png}}
+    /// ```
+    pub fn logo<S: StringOrBuilder>(&mut self, logo: S) -> &mut Self {
+        let logo = logo.merge_into(self);
+        self.content
+            .push_str(&format!("\\logo{{{}}}\n", logo));
+        self
+    }
+
+    /// Adds the `\titlepage` command to the document.
+    ///
+    /// Requires the `beamer` document class. The command must be placed inside a frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.beamer_title_page();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \titlepage
+    /// ```
+    pub fn beamer_title_page(&mut self) -> &mut Self {
+        self.content.push_str("\\titlepage\n");
+        self
+    }
+
+    /// Adds inline math to the document.
+    ///
+    /// The content is never escaped; wrap it in `MathText` to mark it as math content.
+    ///
+    /// # Parameters
+    /// - `content`: The math content.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, MathText};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.inline_math(MathText(String::from("a^2 + b^2 = c^2")));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// $a^2 + b^2 = c^2$
+    /// ```
+    pub fn inline_math<S: StringOrBuilder>(&mut self, content: S) -> &mut Self {
+        let content = content.merge_into(self);
+        self.content
+            .push_str(&format!("${}$", content));
+        self
+    }
+
+    /// Adds unnumbered display math to the document.
+    ///
+    /// The content is never escaped; wrap it in `MathText` to mark it as math content.
+    ///
+    /// # Parameters
+    /// - `content`: The math content.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, MathText};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.display_math(MathText(String::from("\\sum_{i=1}^{n} i = \\frac{n(n+1)}{2}")));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \[
+    /// \sum_{i=1}^{n} i = \frac{n(n+1)}{2}
+    /// \]
+    /// ```
+    pub fn display_math<S: StringOrBuilder>(&mut self, content: S) -> &mut Self {
+        let content = content.merge_into(self);
+        self.content
+            .push_str(&format!("\\[\n{}\n\\]\n", content));
+        self
+    }
+
+    /// Adds a numbered `equation` environment with an optional label to the document.
+    ///
+    /// The label is placed inside the environment, right before `\end{equation}`.
+    ///
+    /// # Parameters
+    /// - `body`: The math content.
+    /// - `label`: The label of the equation, if any.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, MathText};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.equation_labeled(MathText(String::from("e^{i\\pi} + 1 = 0")), Some("eq:euler"));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{equation}
+    /// e^{i\pi} + 1 = 0
+    /// \label{eq:euler}
+    /// \end{equation}
+    /// ```
+    pub fn equation_labeled<S: StringOrBuilder, V: StringOrBuilder>(&mut self, body: S, label: Option<V>) -> &mut Self {
+        let body = body.merge_into(self);
+        self.content
+            .push_str(&format!("\\begin{{equation}}\n{}\n", body));
+        if let Some(label) = label {
+            let label = label.merge_into(self);
+            self.content.push_str(&format!("\\label{{{}}}\n", label));
+        }
+        self.content.push_str("\\end{equation}\n");
+        self
+    }
+
+    /// Adds an unnumbered `equation*` environment to the document.
+    ///
+    /// The `amsmath` package is added to the preamble if it is not already present.
+    ///
+    /// # Parameters
+    /// - `body`: The math content.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, MathText};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.equation_unnumbered(MathText(String::from("a^2 + b^2 = c^2")));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \usepackage{amsmath}
+    /// \begin{equation*}
+    /// a^2 + b^2 = c^2
+    /// \end{equation*}
+    /// ```
+    pub fn equation_unnumbered<S: StringOrBuilder>(&mut self, body: S) -> &mut Self {
+        let body = body.merge_into(self);
+        self.ensure_package("amsmath");
+        self.content.push_str(&format!(
+            "\\begin{{equation*}}\n{}\n\\end{{equation*}}\n",
+            body
+        ));
+        self
+    }
+
+    /// Adds bold text to the document.
+    ///
+    /// # Parameters
+    /// - `text`: The text to make bold.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.text_bold("Bold Text");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \textbf{Bold Text}
+    /// ```
+    pub fn text_bold<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\textbf{{{}}}", text));
+        self
+    }
+
+    /// Adds italic text to the document.
+    ///
+    /// # Parameters
+    /// - `text`: The text to italicize.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.text_italic("Italic Text");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \textit{Italic Text}
+    /// ```
+    pub fn text_italic<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\textit{{{}}}", text));
+        self
+    }
+
+    /// Adds underlined text to the document.
+    ///
+    /// # Parameters
+    /// - `text`: The text to underline.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.text_underline("Underlined Text");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \underline{Underlined Text}
+    /// ```
+    pub fn text_underline<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\underline{{{}}}", text));
+        self
+    }
+
+    /// Adds highlighted text to the document.
+    ///
+    /// Requires the `soul` package. The highlight color is set with `set_highlight_color`.
+    ///
+    /// # Parameters
+    /// - `text`: The text to highlight.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.highlight("Highlighted Text");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \hl{Highlighted Text}
+    /// ```
+    pub fn highlight<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\hl{{{}}}", text));
+        self
+    }
+
+    /// Adds struck-through text to the document.
+    ///
+    /// Requires the `soul` package.
+    ///
+    /// # Parameters
+    /// - `text`: The text to strike through.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.strikethrough("Removed Text");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \st{Removed Text}
+    /// ```
+    pub fn strikethrough<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\st{{{}}}", text));
+        self
+    }
+
+    /// Adds letter-spaced text to the document.
+    ///
+    /// Requires the `soul` package.
+    ///
+    /// # Parameters
+    /// - `text`: The text to letter-space.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.letter_space("Spaced Text");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \so{Spaced Text}
+    /// ```
+    pub fn letter_space<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\so{{{}}}", text));
+        self
+    }
+
+    /// Sets the color used by `highlight`.
+    ///
+    /// Requires the `soul` and `xcolor` packages.
+    ///
+    /// # Parameters
+    /// - `color`: The highlight color.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_highlight_color("yellow");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \sethlcolor{yellow}
+    /// ```
+    pub fn set_highlight_color<S: StringOrBuilder>(&mut self, color: S) -> &mut Self {
+        let color = color.merge_into(self);
+        self.content
+            .push_str(&format!("\\sethlcolor{{{}}}\n", color));
+        self
+    }
+
+    /// Adds text marked as added to the document.
+    ///
+    /// Requires the `changes` package.
+    ///
+    /// # Parameters
+    /// - `text`: The added text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.added("new clause");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \added{new clause}
+    /// ```
+    pub fn added<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\added{{{}}}", text));
+        self
+    }
+
+    /// Adds text marked as deleted to the document.
+    ///
+    /// Requires the `changes` package.
+    ///
+    /// # Parameters
+    /// - `text`: The deleted text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.deleted("old clause");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \deleted{old clause}
+    /// ```
+    pub fn deleted<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\deleted{{{}}}", text));
+        self
+    }
+
+    /// Adds text marked as replacing other text to the document.
+    ///
+    /// Requires the `changes` package.
+    ///
+    /// # Parameters
+    /// - `new`: The new text.
+    /// - `old`: The replaced text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.replaced("thirty days", "two weeks");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \replaced{thirty days}{two weeks}
+    /// ```
+    pub fn replaced<S: StringOrBuilder, V: StringOrBuilder>(&mut self, new: S, old: V) -> &mut Self {
+        let new = new.merge_into(self);
+        let old = old.merge_into(self);
+        self.content.push_str(&format!(
+            "\\replaced{{{}}}{{{}}}",
+            new,
+            old
+        ));
+        self
+    }
+
+    /// Adds the new version of a text, marking the words changed since the old version.
+    ///
+    /// The texts are compared word by word; removed words are wrapped in `\deleted` and
+    /// inserted words in `\added`. Whitespace between words is normalized to single spaces.
+    /// Requires the `changes` package.
+    ///
+    /// # Parameters
+    /// - `old`: The old version of the text.
+    /// - `new`: The new version of the text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.mark_changes("Payment is due in two weeks.", "Payment is due in thirty days.");
+    /// assert_eq!(
+    ///     builder.build_document(),
+    ///     "Payment is due in \\deleted{two weeks.} \\added{thirty days.}"
+    /// );
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// Payment is due in \deleted{two weeks.} \added{thirty days.}
+    /// ```
+    pub fn mark_changes(&mut self, old: &str, new: &str) -> &mut Self {
+        let marked = utils::word_diff(old, new)
+            .into_iter()
+            .map(|run| match run {
+                utils::WordDiff::Same(words) => words.join(" "),
+                utils::WordDiff::Deleted(words) => format!("\\deleted{{{}}}", words.join(" ")),
+                utils::WordDiff::Added(words) => format!("\\added{{{}}}", words.join(" ")),
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        self.content.push_str(&marked);
+        self
+    }
+
+    /// Adds a new line to the document.
+    ///
+    /// `\\` is fragile and breaks in moving arguments such as section titles or captions;
+    /// use `line_break_protected` there instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.new_line();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \\
+    /// ```
+    pub fn new_line(&mut self) -> &mut Self {
+        self.content.push_str("\\\\\n");
+        self
+    }
+
+    /// Adds a protected new line, which is safe to use in moving arguments.
+    ///
+    /// Line breaks in the titles of sections and in captions are protected automatically,
+    /// so this is mostly needed for content passed to other commands.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.section_raw(|b: &mut ContentBuilder| {
+    ///     b.add_literal("A long title").line_break_protected().add_literal("over two lines");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \section{A long title\protect\\
+    /// over two lines}
+    /// ```
+    pub fn line_break_protected(&mut self) -> &mut Self {
+        self.content.push_str("\\protect\\\\\n");
+        self
+    }
+
+    /// Adds a caption to a float environment such as `figure` or `table`.
+    ///
+    /// The text is escaped with `merge_escaped`; use `caption_raw` to embed LaTeX commands.
+    /// Line breaks (`\\`) in the caption are protected automatically.
+    ///
+    /// # Parameters
+    /// - `text`: The caption text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.caption("Measured values").label("fig:values");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \caption{Measured values}
+    /// \label{fig:values}
+    /// ```
+    pub fn caption<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        self.caption_short(text, None::<&str>)
+    }
+
+    /// Adds a caption with an optional short form used in the list of figures or tables.
+    ///
+    /// Both the text and the short form are escaped with `merge_escaped`; use `caption_short_raw`
+    /// to embed LaTeX commands.
+    ///
+    /// # Parameters
+    /// - `text`: The caption text.
+    /// - `short`: An optional short caption for the list of figures or tables.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.caption_short("Measured values over ten runs", Some("Measured values"));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \caption[Measured values]{Measured values over ten runs}
+    /// ```
+    pub fn caption_short<S: StringOrBuilder, V: StringOrBuilder>(&mut self, text: S, short: Option<V>) -> &mut Self {
+        let text = text.merge_escaped_into(self);
+        let short = short.map(|short| short.merge_escaped_into(self));
+        self.caption_from(&text, short.as_deref())
+    }
+
+    /// Adds a caption with an optional short form without escaping either of them.
+    ///
+    /// # Parameters
+    /// - `text`: The caption, as LaTeX code.
+    /// - `short`: An optional short caption for the list of figures or tables, as LaTeX code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.caption_short_raw("Runtime of $O(n \\log n)$ sorting", Some("$O(n \\log n)$ sorting"));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \caption[$O(n \log n)$ sorting]{Runtime of $O(n \log n)$ sorting}
+    /// ```
+    pub fn caption_short_raw<S: StringOrBuilder, V: StringOrBuilder>(&mut self, text: S, short: Option<V>) -> &mut Self {
+        let text = text.merge_into(self);
+        let short = short.map(|short| short.merge_into(self));
+        self.caption_from(&text, short.as_deref())
+    }
+
+    fn caption_from(&mut self, text: &str, short: Option<&str>) -> &mut Self {
+        let short_str = match short {
+            Some(short) => format!("[{}]", utils::protect_line_breaks(short)),
+            None => String::new(),
+        };
+        self.content
+            .push_str(&format!("\\caption{}{{{}}}\n", short_str, utils::protect_line_breaks(text)));
+        self
+    }
+
+    /// Adds a caption to a float environment without escaping it.
+    ///
+    /// # Parameters
+    /// - `text`: The caption, as LaTeX code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.caption_raw("Growth of $O(n \\log n)$ algorithms");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \caption{Growth of $O(n \log n)$ algorithms}
+    /// ```
+    pub fn caption_raw<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        self.caption_short_raw(text, None::<&str>)
+    }
+
+    /// Adds an epigraph, a quotation with its source, to the document.
+    ///
+    /// Requires the `epigraph` package.
+    ///
+    /// # Parameters
+    /// - `text`: The quotation.
+    /// - `source`: The attribution of the quotation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.epigraph("Premature optimization is the root of all evil.", "Donald Knuth");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \epigraph{Premature optimization is the root of all evil.}{Donald Knuth}
+    /// ```
+    pub fn epigraph<S: StringOrBuilder, V: StringOrBuilder>(&mut self, text: S, source: V) -> &mut Self {
+        let text = text.merge_into(self);
+        let source = source.merge_into(self);
+        self.content.push_str(&format!(
+            "\\epigraph{{{}}}{{{}}}\n",
+            text,
+            source
+        ));
+        self
+    }
+
+    /// Sets the width of epigraphs in the preamble.
+    ///
+    /// Requires the `epigraph` package.
+    ///
+    /// # Parameters
+    /// - `width`: The width of epigraphs.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, Length};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_epigraph_width(Length::textwidth(0.6));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \setlength{\epigraphwidth}{0.6\textwidth}
+    /// ```
+    pub fn set_epigraph_width<S: StringOrBuilder>(&mut self, width: S) -> &mut Self {
+        let width = width.merge_into(self);
+        self.push_preamble(&format!("\\setlength{{\\epigraphwidth}}{{{}}}\n", width));
+        self
+    }
+
+    /// Adds a label to the document.
+    ///
+    /// # Parameters
+    /// - `label`: The label text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.label("sec:intro");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \label{sec:intro}
+    /// ```
+    pub fn label<S: StringOrBuilder>(&mut self, label: S) -> &mut Self {
+        let label = label.merge_into(self);
+        self.content
+            .push_str(&format!("\\label{{{}}}\n", label));
+        self
+    }
+
+    /// Adds a section to the document.
+    ///
+    /// The title is escaped with `merge_escaped`, so special characters such as `&` or `_`
+    /// are typeset literally. Use `section_raw` to embed LaTeX commands. The title must not
+    /// contain a paragraph break, which can be checked with `utils::has_paragraph_break`.
+    /// Line breaks (`\\`) in the title are protected automatically.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the section.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.section("Introduction");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \section{Introduction}
+    /// ```
+    pub fn section<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        self.section_ex(title, true)
+    }
+
+    /// Adds a section to the document, optionally unnumbered.
+    ///
+    /// Unnumbered sections use the starred form and are not added to the table of contents.
+    /// The title is escaped like in `section`.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the section.
+    /// - `numbered`: Whether the section is numbered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.section_ex("Preface", false);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \section*{Preface}
+    /// ```
+    pub fn section_ex<S: StringOrBuilder>(&mut self, title: S, numbered: bool) -> &mut Self {
+        let title = title.merge_escaped_into(self);
+        self.heading("section", &title, numbered)
+    }
+
+    /// Adds a numbered section to the document without escaping its title.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the section, as LaTeX code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.section_raw("The \\texttt{main} function");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \section{The \texttt{main} function}
+    /// ```
+    pub fn section_raw<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        self.section_ex_raw(title, true)
+    }
+
+    /// Adds a section to the document without escaping its title, optionally unnumbered.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the section, as LaTeX code.
+    /// - `numbered`: Whether the section is numbered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.section_ex_raw("About \\LaTeX", false);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \section*{About \LaTeX}
+    /// ```
+    pub fn section_ex_raw<S: StringOrBuilder>(&mut self, title: S, numbered: bool) -> &mut Self {
+        let title = title.merge_into(self);
+        self.heading("section", &title, numbered)
+    }
+
+    /// Adds a subsection to the document.
+    ///
+    /// The title is escaped with `merge_escaped`, so special characters such as `&` or `_`
+    /// are typeset literally. Use `subsection_raw` to embed LaTeX commands.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsection.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsection("Background");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsection{Background}
+    /// ```
+    pub fn subsection<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        self.subsection_ex(title, true)
+    }
+
+    /// Adds a subsection to the document, optionally unnumbered.
+    ///
+    /// Unnumbered subsections use the starred form and are not added to the table of contents.
+    /// The title is escaped like in `subsection`.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsection.
+    /// - `numbered`: Whether the subsection is numbered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsection_ex("Preface", false);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsection*{Preface}
+    /// ```
+    pub fn subsection_ex<S: StringOrBuilder>(&mut self, title: S, numbered: bool) -> &mut Self {
+        let title = title.merge_escaped_into(self);
+        self.heading("subsection", &title, numbered)
+    }
+
+    /// Adds a numbered subsection to the document without escaping its title.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsection, as LaTeX code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsection_raw("The \\texttt{main} function");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsection{The \texttt{main} function}
+    /// ```
+    pub fn subsection_raw<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        self.subsection_ex_raw(title, true)
+    }
+
+    /// Adds a subsection to the document without escaping its title, optionally unnumbered.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsection, as LaTeX code.
+    /// - `numbered`: Whether the subsection is numbered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsection_ex_raw("About \\LaTeX", false);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsection*{About \LaTeX}
+    /// ```
+    pub fn subsection_ex_raw<S: StringOrBuilder>(&mut self, title: S, numbered: bool) -> &mut Self {
+        let title = title.merge_into(self);
+        self.heading("subsection", &title, numbered)
+    }
+
+    /// Adds a subsubsection to the document.
+    ///
+    /// The title is escaped with `merge_escaped`, so special characters such as `&` or `_`
+    /// are typeset literally. Use `subsubsection_raw` to embed LaTeX commands.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsubsection.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsubsection("Details");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsubsection{Details}
+    /// ```
+    pub fn subsubsection<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        self.subsubsection_ex(title, true)
+    }
+
+    /// Adds a subsubsection to the document, optionally unnumbered.
+    ///
+    /// Unnumbered subsubsections use the starred form and are not added to the table of contents.
+    /// The title is escaped like in `subsubsection`.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsubsection.
+    /// - `numbered`: Whether the subsubsection is numbered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsubsection_ex("Preface", false);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsubsection*{Preface}
+    /// ```
+    pub fn subsubsection_ex<S: StringOrBuilder>(&mut self, title: S, numbered: bool) -> &mut Self {
+        let title = title.merge_escaped_into(self);
+        self.heading("subsubsection", &title, numbered)
+    }
+
+    /// Adds a numbered subsubsection to the document without escaping its title.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsubsection, as LaTeX code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsubsection_raw("The \\texttt{main} function");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsubsection{The \texttt{main} function}
+    /// ```
+    pub fn subsubsection_raw<S: StringOrBuilder>(&mut self, title: S) -> &mut Self {
+        self.subsubsection_ex_raw(title, true)
+    }
+
+    /// Adds a subsubsection to the document without escaping its title, optionally unnumbered.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the subsubsection, as LaTeX code.
+    /// - `numbered`: Whether the subsubsection is numbered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subsubsection_ex_raw("About \\LaTeX", false);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subsubsection*{About \LaTeX}
+    /// ```
+    pub fn subsubsection_ex_raw<S: StringOrBuilder>(&mut self, title: S, numbered: bool) -> &mut Self {
+        let title = title.merge_into(self);
+        self.heading("subsubsection", &title, numbered)
+    }
+
+    fn heading(&mut self, command: &str, title: &str, numbered: bool) -> &mut Self {
+        let star = if numbered { "" } else { "*" };
+        self.content
+            .push_str(&format!("\\{}{}{{{}}}\n", command, star, utils::protect_line_breaks(title)));
+        self
+    }
+
+    /// Adds a paragraph to the document.
+    ///
+    /// # Parameters
+    /// - `text`: The text of the paragraph.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.paragraph("This is a paragraph.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \paragraph{This is a paragraph.}
+    /// ```
+    pub fn paragraph<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\paragraph{{{}}}\n", text));
+        self
+    }
+
+    /// Adds a subparagraph to the document.
+    ///
+    /// # Parameters
+    /// - `text`: The text of the subparagraph.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.subparagraph("This is a subparagraph.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \subparagraph{This is a subparagraph.}
+    /// ```
+    pub fn subparagraph<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\subparagraph{{{}}}\n", text));
+        self
+    }
+
+    /// Adds a footnote to the document.
+    ///
+    /// The text must not contain a paragraph break, which can be checked with
+    /// `utils::has_paragraph_break`.
+    ///
+    /// # Parameters
+    /// - `text`: The text of the footnote.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.footnote("This is a footnote.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \footnote{This is a footnote.}
+    /// ```
+    pub fn footnote<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        let text = text.merge_into(self);
+        self.content
+            .push_str(&format!("\\footnote{{{}}}", text));
+        self
+    }
+
+    /// Adds a footnote mark without the footnote text, e.g. inside a table.
+    ///
+    /// # Parameters
+    /// - `number`: An optional explicit footnote number.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.footnote_mark(None).footnote_mark(Some(3));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \footnotemark\footnotemark[3]
+    /// ```
+    pub fn footnote_mark(&mut self, number: Option<u32>) -> &mut Self {
+        let number_str = number.map_or(String::new(), |n| format!("[{}]", n));
+        self.content
+            .push_str(&format!("\\footnotemark{}", number_str));
+        self
+    }
+
+    /// Adds the text of a footnote previously marked with `footnote_mark`.
+    ///
+    /// # Parameters
+    /// - `text`: The text of the footnote.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.footnote_text("This is a footnote.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \footnotetext{This is a footnote.}
+    /// ```
+    pub fn footnote_text<S: StringOrBuilder>(&mut self, text: S) -> &mut Self {
+        self.footnote_text_numbered(text, None)
+    }
+
+    /// Adds the text of a footnote previously marked with `footnote_mark`, with an optional number.
+    ///
+    /// # Parameters
+    /// - `text`: The text of the footnote.
+    /// - `number`: An optional explicit footnote number.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.footnote_text_numbered("This is a footnote.", Some(3));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \footnotetext[3]{This is a footnote.}
+    /// ```
+    pub fn footnote_text_numbered<S: StringOrBuilder>(&mut self, text: S, number: Option<u32>) -> &mut Self {
+        let text = text.merge_into(self);
+        let number_str = number.map_or(String::new(), |n| format!("[{}]", n));
+        self.content.push_str(&format!(
+            "\\footnotetext{}{{{}}}\n",
+            number_str,
+            text
+        ));
+        self
+    }
+
+    /// Adds a PDF sticky-note annotation to the document.
+    ///
+    /// Requires the `pdfcomment` package.
+    ///
+    /// # Parameters
+    /// - `note`: The text of the annotation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.pdf_comment("Please check this value.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \pdfcomment{Please check this value.}
+    /// ```
+    pub fn pdf_comment<S: StringOrBuilder>(&mut self, note: S) -> &mut Self {
+        let note = note.merge_into(self);
+        self.content
+            .push_str(&format!("\\pdfcomment{{{}}}", note));
+        self
+    }
+
+    /// Adds a PDF annotation placed in the margin to the document.
+    ///
+    /// Requires the `pdfcomment` package.
+    ///
+    /// # Parameters
+    /// - `note`: The text of the annotation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.pdf_margin_comment("Reworded.");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \pdfmargincomment{Reworded.}
+    /// ```
+    pub fn pdf_margin_comment<S: StringOrBuilder>(&mut self, note: S) -> &mut Self {
+        let note = note.merge_into(self);
+        self.content
+            .push_str(&format!("\\pdfmargincomment{{{}}}", note));
+        self
+    }
+
+    /// Adds a citation to the document.
+    ///
+    /// # Parameters
+    /// - `citation`: The citation key.
+    /// - `subcitation`: An optional subcitation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.cite("doe2020", Some("p. 42"));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \cite[p. 42]{doe2020}
+    /// ```
+    pub fn cite<S: StringOrBuilder, V: StringOrBuilder>(&mut self, citation: S, subcitation: Option<V>) -> &mut Self {
+        let citation = citation.merge_into(self);
+        let subcitation_str = match subcitation {
+            Some(sub) => format!("[{}]", sub.merge_into(self)),
+            None => String::new(),
+        };
+        self.content
+            .push_str(&format!("\\cite{}{{{}}}", subcitation_str, citation));
+        self
+    }
+
+    /// Adds only the author(s) of a citation to the document.
+    ///
+    /// Requires the `natbib` package.
+    ///
+    /// # Parameters
+    /// - `key`: The citation key.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.cite_author("doe2020");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \citeauthor{doe2020}
+    /// ```
+    pub fn cite_author<S: StringOrBuilder>(&mut self, key: S) -> &mut Self {
+        let key = key.merge_into(self);
+        self.content
+            .push_str(&format!("\\citeauthor{{{}}}", key));
+        self
+    }
+
+    /// Adds only the year of a citation to the document.
+    ///
+    /// Requires the `natbib` package.
+    ///
+    /// # Parameters
+    /// - `key`: The citation key.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.cite_year("doe2020");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \citeyear{doe2020}
+    /// ```
+    pub fn cite_year<S: StringOrBuilder>(&mut self, key: S) -> &mut Self {
+        let key = key.merge_into(self);
+        self.content
+            .push_str(&format!("\\citeyear{{{}}}", key));
+        self
+    }
+
+    /// Adds only the year of a citation, in parentheses to the document.
+    ///
+    /// Requires the `natbib` package.
+    ///
+    /// # Parameters
+    /// - `key`: The citation key.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.cite_year_par("doe2020");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \citeyearpar{doe2020}
+    /// ```
+    pub fn cite_year_par<S: StringOrBuilder>(&mut self, key: S) -> &mut Self {
+        let key = key.merge_into(self);
+        self.content
+            .push_str(&format!("\\citeyearpar{{{}}}", key));
+        self
+    }
+
+    /// Adds a bibliography entry to the bibliography without citing it in the text.
+    ///
+    /// # Parameters
+    /// - `key`: The citation key.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.nocite("doe2020");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \nocite{doe2020}
+    /// ```
+    pub fn nocite<S: StringOrBuilder>(&mut self, key: S) -> &mut Self {
+        let key = key.merge_into(self);
+        self.content
+            .push_str(&format!("\\nocite{{{}}}\n", key));
+        self
+    }
+
+    /// Adds all entries of the bibliography database without citing them in the text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.nocite_all();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \nocite{*}
+    /// ```
+    pub fn nocite_all(&mut self) -> &mut Self {
+        self.nocite("*")
+    }
+
+    /// Adds a bibliography entry, for use inside the `thebibliography` environment.
+    ///
+    /// The key is an identifier and is emitted as-is, while the text is escaped
+    /// with `merge_escaped`.
+    ///
+    /// # Parameters
+    /// - `key`: The citation key of the entry.
+    /// - `text`: The descriptive text of the entry.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, Environment, TheBubliographyParams};
+    ///
+    /// let params = TheBubliographyParams::new("9");
+    /// let mut builder = ContentBuilder::new();
+    /// builder.env(Environment::TheBibliography(&params), |b: &mut ContentBuilder| {
+    ///     b.bib_item("knuth1984", "D. Knuth, The TeXbook & more, 1984.");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{thebibliography}{9}
+    /// \bibitem{knuth1984} D. Knuth, The TeXbook \& more, 1984.
+    /// \end{thebibliography}
+    /// ```
+    pub fn bib_item<S: StringOrBuilder, V: StringOrBuilder>(&mut self, key: S, text: V) -> &mut Self {
+        let key = key.merge_into(self);
+        let text = text.merge_escaped_into(self);
+        self.content
+            .push_str(&format!("\\bibitem{{{}}} {}\n", key, text));
+        self
+    }
+
+    /// Adds a complete `thebibliography` environment rendered from the given entries.
+    ///
+    /// Each entry is formatted as "authors, title, publisher, year." with missing parts left out,
+    /// and the text is escaped like in `bib_item`.
+    ///
+    /// # Parameters
+    /// - `entries`: The bibliography entries.
+    /// - `widest_label`: The widest label in the bibliography.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{BibEntry, ContentBuilder};
+    ///
+    /// let entries = [
+    ///     BibEntry::new("doe2020", vec![String::from("J. Doe"), String::from("R. Roe")], "A Study", Some(2020)),
+    ///     BibEntry::new("smith", vec![String::from("A. Smith")], "Notes", None),
+    /// ];
+    /// let mut builder = ContentBuilder::new();
+    /// builder.add_bibliography(&entries, "99");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{thebibliography}{99}
+    /// \bibitem{doe2020} J. Doe and R. Roe, A Study, 2020.
+    /// \bibitem{smith} A. Smith, Notes.
+    /// \end{thebibliography}
+    /// ```
+    pub fn add_bibliography(&mut self, entries: &[BibEntry], widest_label: &str) -> &mut Self {
+        self.content
+            .push_str(&format!("\\begin{{thebibliography}}{{{}}}\n", widest_label));
+        for entry in entries {
+            let mut parts = Vec::new();
+            if let Some((last, rest)) = entry.authors.split_last() {
+                if rest.is_empty() {
+                    parts.push(last.clone());
+                } else {
+                    parts.push(format!("{} and {}", rest.join(", "), last));
+                }
+            }
+            parts.push(entry.title.clone());
+            if let Some(publisher) = &entry.publisher {
+                parts.push(publisher.clone());
+            }
+            if let Some(year) = entry.year {
+                parts.push(year.to_string());
+            }
+            self.bib_item(&entry.key, format!("{}.", parts.join(", ")));
+        }
+        self.content.push_str("\\end{thebibliography}\n");
+        self
+    }
+
+    /// Adds a BibTeX bibliography to the document.
+    ///
+    /// # Parameters
+    /// - `bib_file`: The name of the `.bib` file, without extension.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.bibliography("references");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \bibliography{references}
+    /// ```
+    pub fn bibliography<S: StringOrBuilder>(&mut self, bib_file: S) -> &mut Self {
+        let bib_file = bib_file.merge_into(self);
+        self.content
+            .push_str(&format!("\\bibliography{{{}}}\n", bib_file));
+        self
+    }
+
+    /// Sets the BibTeX bibliography style.
+    ///
+    /// # Parameters
+    /// - `style`: The name of the style (e.g. `plain`, `alpha`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.bibliography_style("plain");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \bibliographystyle{plain}
+    /// ```
+    pub fn bibliography_style<S: StringOrBuilder>(&mut self, style: S) -> &mut Self {
+        let style = style.merge_into(self);
+        self.content
+            .push_str(&format!("\\bibliographystyle{{{}}}\n", style));
+        self
+    }
+
+    /// Adds a BibTeX bibliography set in the given font size.
+    ///
+    /// The bibliography is wrapped in a group, so the size does not affect the following text.
+    ///
+    /// # Parameters
+    /// - `bib_file`: The name of the `.bib` file, without extension.
+    /// - `size`: The font size of the bibliography.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, FontSize};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.bibliography_sized("references", FontSize::Small);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// {\small
+    /// \bibliography{references}
+    /// }
+    /// ```
+    pub fn bibliography_sized<S: StringOrBuilder>(&mut self, bib_file: S, size: FontSize) -> &mut Self {
+        self.content.push_str(&format!("{{\\{}\n", size));
+        self.bibliography(bib_file);
+        self.content.push_str("}\n");
+        self
+    }
+
+    /// Sets the font size of the bibliography in the preamble.
+    ///
+    /// Requires the `biblatex` package. For BibTeX, use `bibliography_sized` instead.
+    ///
+    /// # Parameters
+    /// - `size`: The font size of the bibliography.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, FontSize};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.bibliography_font_size(FontSize::Footnotesize);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \renewcommand*{\bibfont}{\footnotesize}
+    /// ```
+    pub fn bibliography_font_size(&mut self, size: FontSize) -> &mut Self {
+        self.push_preamble(&format!("\\renewcommand*{{\\bibfont}}{{\\{}}}\n", size));
+        self
+    }
+
+    /// Adds a reference to a label in the document.
+    ///
+    /// # Parameters
+    /// - `label`: The label to reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.ref_label("sec:intro");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \ref{sec:intro}
+    /// ```
+    pub fn ref_label<S: StringOrBuilder>(&mut self, label: S) -> &mut Self {
+        let label = label.merge_into(self);
+        self.content
+            .push_str(&format!("\\ref{{{}}}", label));
+        self
+    }
+
+    /// Adds the page number of a label to the document.
+    ///
+    /// # Parameters
+    /// - `label`: The label to reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.page_ref("sec:intro");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \pageref{sec:intro}
+    /// ```
+    pub fn page_ref<S: StringOrBuilder>(&mut self, label: S) -> &mut Self {
+        let label = label.merge_into(self);
+        self.content
+            .push_str(&format!("\\pageref{{{}}}", label));
+        self
+    }
+
+    /// Adds a parenthesized reference to an equation to the document.
+    ///
+    /// The `amsmath` package is added to the preamble if it is not already present.
+    ///
+    /// # Parameters
+    /// - `label`: The label to reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.eqref("eq:euler");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \usepackage{amsmath}
+    /// \eqref{eq:euler}
+    /// ```
+    pub fn eqref<S: StringOrBuilder>(&mut self, label: S) -> &mut Self {
+        let label = label.merge_into(self);
+        self.ensure_package("amsmath");
+        self.content
+            .push_str(&format!("\\eqref{{{}}}", label));
+        self
+    }
+
+    /// Adds a reference to a label, prefixed with the type of the referenced item, e.g. "Figure 2".
+    ///
+    /// The `hyperref` package is added to the preamble if it is not already present.
+    ///
+    /// # Parameters
+    /// - `label`: The label to reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.autoref("fig:plot");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \usepackage{hyperref}
+    /// \autoref{fig:plot}
+    /// ```
+    pub fn autoref<S: StringOrBuilder>(&mut self, label: S) -> &mut Self {
+        let label = label.merge_into(self);
+        self.ensure_package("hyperref");
+        self.content
+            .push_str(&format!("\\autoref{{{}}}", label));
+        self
+    }
+
+    /// Adds a reference to the title of a labeled section to the document.
+    ///
+    /// The `nameref` package is added to the preamble if it is not already present.
+    ///
+    /// # Parameters
+    /// - `label`: The label to reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.nameref("sec:intro");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \usepackage{nameref}
+    /// \nameref{sec:intro}
+    /// ```
+    pub fn nameref<S: StringOrBuilder>(&mut self, label: S) -> &mut Self {
+        let label = label.merge_into(self);
+        self.ensure_package("nameref");
+        self.content
+            .push_str(&format!("\\nameref{{{}}}", label));
+        self
+    }
+
+    /// Adds a link with custom text pointing to a label in the document.
+    ///
+    /// Unlike `ref_label`, the label number is not shown. Requires the `hyperref` package.
+    ///
+    /// # Parameters
+    /// - `label`: The label to link to.
+    /// - `text`: The text of the link.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.hyperref_text("sec:intro", "click here");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \hyperref[sec:intro]{click here}
+    /// ```
+    pub fn hyperref_text<S: StringOrBuilder, V: StringOrBuilder>(&mut self, label: S, text: V) -> &mut Self {
+        let label = label.merge_into(self);
+        let text = text.merge_into(self);
+        self.content.push_str(&format!(
+            "\\hyperref[{}]{{{}}}",
+            label,
+            text
+        ));
+        self
+    }
+
+    /// Adds a type-aware reference to a label in the document.
+    ///
+    /// Requires the `cleveref` package, which must be loaded after `hyperref`.
+    ///
+    /// # Parameters
+    /// - `label`: The label to reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.cref("fig:plot");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \cref{fig:plot}
+    /// ```
+    pub fn cref<S: StringOrBuilder>(&mut self, label: S) -> &mut Self {
+        let label = label.merge_into(self);
+        self.content
+            .push_str(&format!("\\cref{{{}}}", label));
+        self
+    }
+
+    /// Adds a type-aware reference to a range of labels in the document.
+    ///
+    /// Requires the `cleveref` package, which must be loaded after `hyperref`.
+    ///
+    /// # Parameters
+    /// - `from`: The first label of the range.
+    /// - `to`: The last label of the range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.cref_range("fig:first", "fig:last");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \crefrange{fig:first}{fig:last}
+    /// ```
+    pub fn cref_range<S: StringOrBuilder, V: StringOrBuilder>(&mut self, from: S, to: V) -> &mut Self {
+        let from = from.merge_into(self);
+        let to = to.merge_into(self);
+        self.content.push_str(&format!(
+            "\\crefrange{{{}}}{{{}}}",
+            from,
+            to
+        ));
+        self
+    }
+
+    /// Adds a type-aware reference to a list of labels in the document.
+    ///
+    /// Requires the `cleveref` package, which must be loaded after `hyperref`.
+    ///
+    /// # Parameters
+    /// - `labels`: The labels to reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.cref_many(["fig:a", "fig:b", "tab:c"]);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \cref{fig:a,fig:b,tab:c}
+    /// ```
+    pub fn cref_many<I, S>(&mut self, labels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: StringOrBuilder,
+    {
+        let labels_str = labels
+            .into_iter()
+            .map(|l| l.merge_into(self))
+            .collect::<Vec<String>>()
+            .join(",");
+        self.content
+            .push_str(&format!("\\cref{{{}}}", labels_str));
+        self
+    }
+
+    /// Adds a hyperlink with custom text to the document.
+    ///
+    /// The `hyperref` package is added to the preamble if it is not already present.
+    /// The URL is escaped with `utils::escape_url`, so `%` and `#` may be passed as-is.
+    ///
+    /// # Parameters
+    /// - `url`: The target URL.
+    /// - `text`: The text of the link.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.href("https://example.com/#docs", "the documentation");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \usepackage{hyperref}
+    /// \href{https://example.com/\#docs}{the documentation}
+    /// ```
+    pub fn href<S: StringOrBuilder, V: StringOrBuilder>(&mut self, url: S, text: V) -> &mut Self {
+        let url = url.merge_into(self);
+        let text = text.merge_into(self);
+        self.ensure_package("hyperref");
+        self.content.push_str(&format!(
+            "\\href{{{}}}{{{}}}",
+            utils::escape_url(&url),
+            text
+        ));
+        self
+    }
+
+    /// Adds a URL to the document.
+    ///
+    /// The `hyperref` package is added to the preamble if it is not already present.
+    /// The URL is escaped with `utils::escape_url`, so `%` and `#` may be passed as-is.
+    ///
+    /// # Parameters
+    /// - `url`: The URL to display.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.url("https://example.com/my_page");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \usepackage{hyperref}
+    /// \url{https://example.com/my_page}
+    /// ```
+    pub fn url<S: StringOrBuilder>(&mut self, url: S) -> &mut Self {
+        let url = url.merge_into(self);
+        self.ensure_package("hyperref");
+        self.content
+            .push_str(&format!("\\url{{{}}}", utils::escape_url(&url)));
+        self
+    }
+
+    /// Adds colored text to the document.
+    ///
+    /// If no `color_model` is given, the model required by a typed color such as `Color::Rgb`
+    /// or `Color::Hex` is used.
+    ///
+    /// # Parameters
+    /// - `text`: The text to color.
+    /// - `color`: The color to apply.
     /// - `color_model`: An optional color model.
     ///
+    /// # Errors
+    /// Returns `LatexError::InvalidColorSpec` if a numeric `color_model` is given and `color`
+    /// does not have the number of numeric components it requires (three for `rgb`/`RGB`,
+    /// four for `cmyk`, one for `gray`), e.g. a color name combined with `rgb`, if a component
+    /// is out of range (`0`–`1` for `rgb`, `cmyk` and `gray`, an integer `0`–`255` for `RGB`),
+    /// or if an `HTML` color is not six hexadecimal digits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{Color, ContentBuilder, ColorModel};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.text_color("Colored Text", "1,0,0", Some(ColorModel::RGB)).unwrap();
+    /// assert!(builder.text_color("Colored Text", "red", Some(ColorModel::RGB)).is_err());
+    /// assert!(builder.text_color("Colored Text", "255,0,0", Some(ColorModel::RGB)).is_err());
+    /// assert!(builder.text_color("Colored Text", "0.5,0,0", Some(ColorModel::RGBFull)).is_err());
+    /// builder.text_color("Brand Text", Color::rgb(0, 128, 255), None).unwrap();
+    /// assert!(builder.build_document().ends_with("\\textcolor[RGB]{0,128,255}{Brand Text}"));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \textcolor[rgb]{1,0,0}{Colored Text}\textcolor[RGB]{0,128,255}{Brand Text}
+    /// ```
+    pub fn text_color<S: StringOrBuilder, V: StringOrBuilder>(
+        &mut self,
+        text: S,
+        color: V,
+        color_model: Option<ColorModel>,
+    ) -> Result<&mut Self, LatexError> {
+        let color_model = color_model.or_else(|| color.color_model());
+        let text = text.merge_into(self);
+        let color = color.merge_into(self);
+        let color_model_str = match color_model {
+            Some(model) => {
+                if !utils::is_valid_color_spec(&model, &color) {
+                    return Err(LatexError::InvalidColorSpec {
+                        model: model.to_string(),
+                        spec: color,
+                    });
+                }
+                format!("[{}]", model.to_string())
+            }
+            None => String::new(),
+        };
+        self.content.push_str(&format!(
+            "\\textcolor{}{{{}}}{{{}}}",
+            color_model_str,
+            color,
+            text
+        ));
+        Ok(self)
+    }
+
+    /// Adds colored text to the document, using the color model required by `color`.
+    ///
+    /// # Parameters
+    /// - `text`: The text to color.
+    /// - `color`: The color to apply.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidColorSpec` if a `Color::Hex` value is not six hexadecimal digits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{Color, ContentBuilder};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .text_color_typed("Hex", Color::hex("#FF8800"))?
+    ///     .text_color_typed("RGB", Color::rgb(0, 128, 255))?
+    ///     .text_color_typed("Named", Color::Red)?;
+    /// # Ok::<(), rusttex::LatexError>(())
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \textcolor[HTML]{FF8800}{Hex}\textcolor[RGB]{0,128,255}{RGB}\textcolor{red}{Named}
+    /// ```
+    pub fn text_color_typed<S: StringOrBuilder>(&mut self, text: S, color: Color) -> Result<&mut Self, LatexError> {
+        self.text_color(text, color, None)
+    }
+
+    /// Defines a color derived from other colors in the preamble.
+    ///
+    /// Requires the `xcolor` package.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the new color.
+    /// - `expr`: The color expression, as a string like `blue!30!white` or a `ColorExpr`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{Color, ContentBuilder};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .color_let("lightblue", "blue!30")
+    ///     .color_let("paleblue", Color::Blue.mix(30)?.with(Color::White)?);
+    /// # Ok::<(), rusttex::LatexError>(())
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \colorlet{lightblue}{blue!30}
+    /// \colorlet{paleblue}{blue!30!white}
+    /// ```
+    pub fn color_let<S: StringOrBuilder, V: StringOrBuilder>(&mut self, name: S, expr: V) -> &mut Self {
+        let name = name.merge_into(self);
+        let expr = expr.merge_into(self);
+        self.push_preamble(&format!(
+            "\\colorlet{{{}}}{{{}}}\n",
+            name,
+            expr
+        ));
+        self
+    }
+
+    /// Defines a custom color in the preamble.
+    ///
+    /// Requires the `xcolor` package.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the color.
+    /// - `model`: The color model of the specification.
+    /// - `spec`: The specification of the color, e.g. `0.2,0.3,0.8` for `rgb`.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidColorSpec` if `spec` does not have the number of numeric
+    /// components required by `model` (three for `rgb`/`RGB`, four for `cmyk`, one for `gray`),
+    /// if a component is out of range (`0`–`1` for `rgb`, `cmyk` and `gray`, an integer
+    /// `0`–`255` for `RGB`), or if an `HTML` specification is not six hexadecimal digits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, ColorModel};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.define_color("myblue", ColorModel::RGB, "0.2,0.3,0.8").unwrap();
+    /// assert!(builder.define_color("mygray", ColorModel::Gray, "0.2,0.3").is_err());
+    /// assert!(builder.define_color("mygray", ColorModel::Gray, "1.5").is_err());
+    /// assert!(builder.define_color("myred", ColorModel::RGBFull, "256,0,0").is_err());
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \definecolor{myblue}{rgb}{0.2,0.3,0.8}
+    /// ```
+    pub fn define_color<S: StringOrBuilder, V: StringOrBuilder>(
+        &mut self,
+        name: S,
+        model: ColorModel,
+        spec: V,
+    ) -> Result<&mut Self, LatexError> {
+        let name = name.merge_into(self);
+        let spec = spec.merge_into(self);
+        if !utils::is_valid_color_spec(&model, &spec) {
+            return Err(LatexError::InvalidColorSpec {
+                model: model.to_string(),
+                spec,
+            });
+        }
+        self.colors.insert(name.clone());
+        self.push_preamble(&format!(
+            "\\definecolor{{{}}}{{{}}}{{{}}}\n",
+            name,
+            model.to_string(),
+            spec
+        ));
+        Ok(self)
+    }
+
+    /// Adds horizontal space to the document.
+    ///
+    /// # Parameters
+    /// - `length`: The length of the space, as a string or a typed `Length`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.hspace("1cm");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \hspace{1cm}
+    /// ```
+    pub fn hspace<S: StringOrBuilder>(&mut self, length: S) -> &mut Self {
+        let length = length.merge_into(self);
+        self.content.push_str(&format!("\\hspace{{{}}}", length));
+        self
+    }
+
+    /// Adds vertical space to the document.
+    ///
+    /// # Parameters
+    /// - `length`: The length of the space, as a string or a typed `Length`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.vspace("1cm");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \vspace{1cm}
+    /// ```
+    pub fn vspace<S: StringOrBuilder>(&mut self, length: S) -> &mut Self {
+        let length = length.merge_into(self);
+        self.content.push_str(&format!("\\vspace{{{}}}", length));
+        self
+    }
+
+    /// Includes another LaTeX file in the document.
+    ///
+    /// # Parameters
+    /// - `filename`: The name of the file to include.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.include("otherfile");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \include{otherfile}
+    /// ```
+    pub fn include<S: StringOrBuilder>(&mut self, filename: S) -> &mut Self {
+        let filename = filename.merge_into(self);
+        self.content
+            .push_str(&format!("\\include{{{}}}\n", filename));
+        self
+    }
+
+    /// Restricts `include` to the given files in the preamble, to speed up partial compilation.
+    ///
+    /// An empty list emits `\includeonly{}`, which suppresses all included files.
+    ///
+    /// # Parameters
+    /// - `files`: The names of the files to include.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .include_only(["ch1", "ch2"])
+    ///     .begin_document()
+    ///     .include("ch1")
+    ///     .include("ch2")
+    ///     .include("ch3")
+    ///     .end_document();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \includeonly{ch1,ch2}
+    /// \begin{document}
+    /// \include{ch1}
+    /// \include{ch2}
+    /// \include{ch3}
+    /// \end{document}
+    /// ```
+    pub fn include_only<I, S>(&mut self, files: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: StringOrBuilder,
+    {
+        let files = files
+            .into_iter()
+            .map(|f| f.merge_into(self))
+            .collect::<Vec<String>>()
+            .join(",");
+        self.push_preamble(&format!("\\includeonly{{{}}}\n", files));
+        self
+    }
+
+    /// Inputs another LaTeX file in the document.
+    ///
+    /// # Parameters
+    /// - `filename`: The name of the file to input.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.input("otherfile");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \input{otherfile}
+    /// ```
+    pub fn input<S: StringOrBuilder>(&mut self, filename: S) -> &mut Self {
+        let filename = filename.merge_into(self);
+        self.content
+            .push_str(&format!("\\input{{{}}}\n", filename));
+        self
+    }
+
+    /// Includes pages of an existing PDF file in the document.
+    ///
+    /// Requires the `pdfpages` package.
+    ///
+    /// # Parameters
+    /// - `file`: The PDF file to include.
+    /// - `options`: A list of options, e.g. `pages=-` for all pages or `pages={1,3-5}`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.include_pdf("appendix.pdf", options!["pages=-"]);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \includepdf[pages=-]{appendix.pdf}
+    /// ```
+    pub fn include_pdf<S: StringOrBuilder>(&mut self, file: S, options: Vec<Box<dyn ToString>>) -> &mut Self {
+        let file = file.merge_into(self);
+        if options.is_empty() {
+            self.content
+                .push_str(&format!("\\includepdf{{{}}}\n", file));
+        } else {
+            let options_str = options
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            self.content.push_str(&format!(
+                "\\includepdf[{}]{{{}}}\n",
+                options_str,
+                file
+            ));
+        }
+        self
+    }
+
+    /// Adds a QR code encoding the given data to the document.
+    ///
+    /// Requires the `qrcode` package, which renders the code at compile time.
+    ///
+    /// # Parameters
+    /// - `data`: The data to encode, e.g. a URL.
+    /// - `options`: A list of options, e.g. `height=2cm`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.qr_code("https://example.com", options!["height=2cm"]);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \qrcode[height=2cm]{https://example.com}
+    /// ```
+    pub fn qr_code<S: StringOrBuilder>(&mut self, data: S, options: Vec<Box<dyn ToString>>) -> &mut Self {
+        let data = data.merge_into(self);
+        if options.is_empty() {
+            self.content
+                .push_str(&format!("\\qrcode{{{}}}", data));
+        } else {
+            let options_str = options
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            self.content.push_str(&format!(
+                "\\qrcode[{}]{{{}}}",
+                options_str,
+                data
+            ));
+        }
+        self
+    }
+
+    /// Adds a barcode to the document.
+    ///
+    /// Requires the `pst-barcode` package. As it is based on PSTricks, the document has to be
+    /// compiled with `latex` + `dvips`, `xelatex`, or `pdflatex` together with `auto-pst-pdf`.
+    ///
+    /// # Parameters
+    /// - `code`: The data to encode.
+    /// - `kind`: The barcode symbology.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{BarcodeKind, ContentBuilder};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.barcode("9780201529838", BarcodeKind::EAN13);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \psbarcode{9780201529838}{}{ean13}
+    /// ```
+    pub fn barcode<S: StringOrBuilder>(&mut self, code: S, kind: BarcodeKind) -> &mut Self {
+        let code = code.merge_into(self);
+        self.content.push_str(&format!(
+            "\\psbarcode{{{}}}{{}}{{{}}}",
+            code,
+            kind
+        ));
+        self
+    }
+
+    /// Adds a `verbatim` environment to the document.
+    ///
+    /// The content is written exactly as given, without escaping, preserving all whitespace
+    /// and newlines. It is the caller's responsibility that the content does not contain
+    /// `\end{verbatim}`.
+    ///
+    /// # Parameters
+    /// - `content`: The verbatim content.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.verbatim_block("let x = 50% & {y};");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{verbatim}
+    /// let x = 50% & {y};
+    /// \end{verbatim}
+    /// ```
+    pub fn verbatim_block(&mut self, content: &str) -> &mut Self {
+        self.content.push_str("\\begin{verbatim}\n");
+        self.content.push_str(content);
+        if !content.ends_with('\n') {
+            self.content.push('\n');
+        }
+        self.content.push_str("\\end{verbatim}\n");
+        self
+    }
+
+    /// Adds a `Verbatim` environment from the `fancyvrb` package to the document.
+    ///
+    /// The content is written exactly as given, without escaping or reindenting.
+    /// Requires the `fancyvrb` package.
+    ///
+    /// # Parameters
+    /// - `content`: The verbatim content.
+    /// - `options`: A list of options, e.g. `numbers=left`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.fancy_verbatim("fn main() {}", options!["numbers=left"]);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{Verbatim}[numbers=left]
+    /// fn main() {}
+    /// \end{Verbatim}
+    /// ```
+    pub fn fancy_verbatim<S: StringOrBuilder>(&mut self, content: S, options: Vec<Box<dyn ToString>>) -> &mut Self {
+        let content = content.merge_into(self);
+        if options.is_empty() {
+            self.content.push_str("\\begin{Verbatim}\n");
+        } else {
+            let options_str = options
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            self.content
+                .push_str(&format!("\\begin{{Verbatim}}[{}]\n", options_str));
+        }
+        self.content.push_str(&format!("{}\n", content));
+        self.content.push_str("\\end{Verbatim}\n");
+        self
+    }
+
+    /// Includes the contents of a file verbatim in the document.
+    ///
+    /// Unlike `input`, the file is shown literally rather than interpreted as LaTeX.
+    /// Requires the `fancyvrb` package.
+    ///
+    /// # Parameters
+    /// - `file`: The file to include.
+    /// - `options`: A list of options, e.g. `numbers=left`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.verbatim_input("config.toml", options!["numbers=left"]);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \VerbatimInput[numbers=left]{config.toml}
+    /// ```
+    pub fn verbatim_input<S: StringOrBuilder>(&mut self, file: S, options: Vec<Box<dyn ToString>>) -> &mut Self {
+        let file = file.merge_into(self);
+        if options.is_empty() {
+            self.content
+                .push_str(&format!("\\VerbatimInput{{{}}}\n", file));
+        } else {
+            let options_str = options
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            self.content.push_str(&format!(
+                "\\VerbatimInput[{}]{{{}}}\n",
+                options_str,
+                file
+            ));
+        }
+        self
+    }
+
+    /// Includes a source file with syntax highlighting in the document.
+    ///
+    /// Requires the `listings` package.
+    ///
+    /// # Parameters
+    /// - `file`: The source file to include.
+    /// - `language`: An optional language used for highlighting, e.g. `Rust`.
+    /// - `options`: A list of additional options, e.g. `firstline=10`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.listing_input("src/main.rs", Some("Rust"), options!["numbers=left"]);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \lstinputlisting[language=Rust,numbers=left]{src/main.rs}
+    /// ```
+    pub fn listing_input<S: StringOrBuilder>(
+        &mut self,
+        file: S,
+        language: Option<&str>,
+        options: Vec<Box<dyn ToString>>,
+    ) -> &mut Self {
+        let file = file.merge_into(self);
+        let options = language
+            .map(|l| format!("language={}", l))
+            .into_iter()
+            .chain(options.iter().map(|o| o.to_string()))
+            .collect::<Vec<String>>();
+        if options.is_empty() {
+            self.content
+                .push_str(&format!("\\lstinputlisting{{{}}}\n", file));
+        } else {
+            self.content.push_str(&format!(
+                "\\lstinputlisting[{}]{{{}}}\n",
+                options.join(","),
+                file
+            ));
+        }
+        self
+    }
+
+    /// Adds a `\frontmatter` command, starting the front matter, numbered with roman numerals and with unnumbered chapters.
+    ///
+    /// Only available in the `book` document class.
+    ///
+    /// # Panics
+    /// In debug builds, panics if the document class was set to a class other than `book`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, DocumentClass, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .set_document_class(DocumentClass::Book, options![])
+    ///     .front_matter();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \documentclass{book}
+    /// \frontmatter
+    /// ```
+    pub fn front_matter(&mut self) -> &mut Self {
+        self.book_division("frontmatter")
+    }
+
+    /// Adds a `\mainmatter` command, starting the main matter, numbered with arabic numerals.
+    ///
+    /// Only available in the `book` document class.
+    ///
+    /// # Panics
+    /// In debug builds, panics if the document class was set to a class other than `book`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, DocumentClass, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .set_document_class(DocumentClass::Book, options![])
+    ///     .main_matter();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \documentclass{book}
+    /// \mainmatter
+    /// ```
+    pub fn main_matter(&mut self) -> &mut Self {
+        self.book_division("mainmatter")
+    }
+
+    /// Adds a `\backmatter` command, starting the back matter, with unnumbered chapters.
+    ///
+    /// Only available in the `book` document class.
+    ///
+    /// # Panics
+    /// In debug builds, panics if the document class was set to a class other than `book`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, DocumentClass, options};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .set_document_class(DocumentClass::Book, options![])
+    ///     .back_matter();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \documentclass{book}
+    /// \backmatter
+    /// ```
+    pub fn back_matter(&mut self) -> &mut Self {
+        self.book_division("backmatter")
+    }
+
+    fn book_division(&mut self, command: &str) -> &mut Self {
+        debug_assert!(
+            matches!(self.document_class.as_deref(), None | Some("book")),
+            "`\\{}` requires the `book` document class",
+            command
+        );
+        self.content.push_str(&format!("\\{}\n", command));
+        self
+    }
+
+    /// Adds a `\phantomsection` anchor, so that hyperref links to a following
+    /// `add_contents_line` entry point to the right place.
+    ///
+    /// Requires the `hyperref` package.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, TocLevel};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .section_ex("Preface", false)
+    ///     .phantom_section()
+    ///     .add_contents_line("toc", TocLevel::Section, "Preface");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \section*{Preface}
+    /// \phantomsection
+    /// \addcontentsline{toc}{section}{Preface}
+    /// ```
+    pub fn phantom_section(&mut self) -> &mut Self {
+        self.content.push_str("\\phantomsection\n");
+        self
+    }
+
+    /// Adds an entry to a table of contents or list file.
+    ///
+    /// # Parameters
+    /// - `file`: The extension of the list file, e.g. `toc`, `lof` or `lot`.
+    /// - `level`: The sectioning level of the entry, as a name or a typed `TocLevel`.
+    /// - `text`: The text of the entry. LaTeX special characters are escaped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.add_contents_line("toc", "chapter", "Notes & References");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \addcontentsline{toc}{chapter}{Notes \& References}
+    /// ```
+    pub fn add_contents_line<S: StringOrBuilder, V: StringOrBuilder>(
+        &mut self,
+        file: &str,
+        level: S,
+        text: V,
+    ) -> &mut Self {
+        let level = level.merge_into(self);
+        let text = text.merge_escaped_into(self);
+        self.content.push_str(&format!(
+            "\\addcontentsline{{{}}}{{{}}}{{{}}}\n",
+            file,
+            level,
+            text
+        ));
+        self
+    }
+
+    /// Adds an `\appendix` command to the document.
+    ///
+    /// Subsequent sections (or chapters in `report` and `book`) are numbered as appendices.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.appendix().section("Proofs");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \appendix
+    /// \section{Proofs}
+    /// ```
+    pub fn appendix(&mut self) -> &mut Self {
+        self.content.push_str("\\appendix\n");
+        self
+    }
+
+    /// Adds a `\clearpage` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.clear_page();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \clearpage
+    /// ```
+    pub fn clear_page(&mut self) -> &mut Self {
+        self.content.push_str("\\clearpage\n");
+        self
+    }
+
+    /// Adds a `\newpage` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.new_page();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \newpage
+    /// ```
+    pub fn new_page(&mut self) -> &mut Self {
+        self.content.push_str("\\newpage\n");
+        self
+    }
+
+    /// Sets the page style of the document from this point on.
+    ///
+    /// # Parameters
+    /// - `style`: The page style.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, PageStyle};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.page_style(PageStyle::Plain);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \pagestyle{plain}
+    /// ```
+    pub fn page_style(&mut self, style: PageStyle) -> &mut Self {
+        self.content
+            .push_str(&format!("\\pagestyle{{{}}}\n", style));
+        self
+    }
+
+    /// Sets the page style of the current page only.
+    ///
+    /// # Parameters
+    /// - `style`: The page style.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, PageStyle};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.maketitle().this_page_style(PageStyle::Empty);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \maketitle
+    /// \thispagestyle{empty}
+    /// ```
+    pub fn this_page_style(&mut self, style: PageStyle) -> &mut Self {
+        self.content
+            .push_str(&format!("\\thispagestyle{{{}}}\n", style));
+        self
+    }
+
+    /// Sets a counter to the given value.
+    ///
+    /// # Parameters
+    /// - `counter`: The counter, as a name or a typed `Counter`.
+    /// - `value`: The new value of the counter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, Counter};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_counter(Counter::Page, 1).set_counter("mycounter", 5);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \setcounter{page}{1}
+    /// \setcounter{mycounter}{5}
+    /// ```
+    pub fn set_counter<S: StringOrBuilder>(&mut self, counter: S, value: i64) -> &mut Self {
+        let counter = counter.merge_into(self);
+        self.content
+            .push_str(&format!("\\setcounter{{{}}}{{{}}}\n", counter, value));
+        self
+    }
+
+    /// Adds a possibly negative amount to a counter.
+    ///
+    /// # Parameters
+    /// - `counter`: The counter, as a name or a typed `Counter`.
+    /// - `delta`: The amount to add to the counter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, Counter};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.add_to_counter(Counter::Section, -1);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \addtocounter{section}{-1}
+    /// ```
+    pub fn add_to_counter<S: StringOrBuilder>(&mut self, counter: S, delta: i64) -> &mut Self {
+        let counter = counter.merge_into(self);
+        self.content
+            .push_str(&format!("\\addtocounter{{{}}}{{{}}}\n", counter, delta));
+        self
+    }
+
+    /// Changes the font size from this point on.
+    ///
+    /// # Parameters
+    /// - `size`: The font size.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, FontSize};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.font_size(FontSize::Small);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \small
+    /// ```
+    pub fn font_size(&mut self, size: FontSize) -> &mut Self {
+        self.content.push_str(&format!("\\{}\n", size));
+        self
+    }
+
+    /// Resets the font size to the normal body size.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.normal_size();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \normalsize
+    /// ```
+    pub fn normal_size(&mut self) -> &mut Self {
+        self.font_size(FontSize::Normalsize)
+    }
+
+    /// Sets the paragraph indentation and spacing from a preset.
+    ///
+    /// # Parameters
+    /// - `style`: The paragraph style to apply.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, ParagraphStyle};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.paragraph_style(ParagraphStyle::Indented);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \setlength{\parindent}{1.5em}
+    /// \setlength{\parskip}{0pt}
+    /// ```
+    pub fn paragraph_style(&mut self, style: ParagraphStyle) -> &mut Self {
+        let (parindent, parskip) = match style {
+            ParagraphStyle::Indented => ("1.5em", "0pt"),
+            ParagraphStyle::Blocked => ("0pt", "1em"),
+        };
+        self.content.push_str(&format!(
+            "\\setlength{{\\parindent}}{{{}}}\n\\setlength{{\\parskip}}{{{}}}\n",
+            parindent, parskip
+        ));
+        self
+    }
+
+    /// Sets the distance between the baselines of consecutive lines.
+    ///
+    /// # Parameters
+    /// - `skip`: The baseline distance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, Length};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_baseline_skip(Length::pt(14.0));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \setlength{\baselineskip}{14pt}
+    /// ```
+    pub fn set_baseline_skip<S: StringOrBuilder>(&mut self, skip: S) -> &mut Self {
+        let skip = skip.merge_into(self);
+        self.content
+            .push_str(&format!("\\setlength{{\\baselineskip}}{{{}}}\n", skip));
+        self
+    }
+
+    /// Scales the line spacing by the given factor.
+    ///
+    /// `\selectfont` is emitted after `\linespread` so the new spacing takes effect immediately.
+    ///
+    /// # Parameters
+    /// - `factor`: The line spacing factor, e.g. `1.3` for roughly one-and-a-half spacing.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_line_spread(1.3);
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \linespread{1.3}\selectfont
+    /// ```
+    pub fn set_line_spread(&mut self, factor: f64) -> &mut Self {
+        self.content
+            .push_str(&format!("\\linespread{{{}}}\\selectfont\n", factor));
+        self
+    }
+
+    /// Adds a `\tableofcontents` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.table_of_contents();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \tableofcontents
+    /// ```
+    pub fn table_of_contents(&mut self) -> &mut Self {
+        self.content.push_str("\\tableofcontents\n");
+        self
+    }
+
+    /// Adds a `\listoffigures` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.list_of_figures();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \listoffigures
+    /// ```
+    pub fn list_of_figures(&mut self) -> &mut Self {
+        self.content.push_str("\\listoffigures\n");
+        self
+    }
+
+    /// Adds a `\listoftables` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.list_of_tables();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \listoftables
+    /// ```
+    pub fn list_of_tables(&mut self) -> &mut Self {
+        self.content.push_str("\\listoftables\n");
+        self
+    }
+
+    /// Sets the title of the table of contents in the preamble.
+    ///
+    /// # Parameters
+    /// - `name`: The title of the table of contents.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_contents_name("Spis treści");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \renewcommand{\contentsname}{Spis treści}
+    /// ```
+    pub fn set_contents_name<S: StringOrBuilder>(&mut self, name: S) -> &mut Self {
+        self.set_name(NameElement::Contents, name)
+    }
+
+    /// Sets the title of the list of figures in the preamble.
+    ///
+    /// # Parameters
+    /// - `name`: The title of the list of figures.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_list_of_figures_name("Spis rysunków");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \renewcommand{\listfigurename}{Spis rysunków}
+    /// ```
+    pub fn set_list_of_figures_name<S: StringOrBuilder>(&mut self, name: S) -> &mut Self {
+        self.set_name(NameElement::ListOfFigures, name)
+    }
+
+    /// Sets the title of the list of tables in the preamble.
+    ///
+    /// # Parameters
+    /// - `name`: The title of the list of tables.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_list_of_tables_name("Spis tabel");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \renewcommand{\listtablename}{Spis tabel}
+    /// ```
+    pub fn set_list_of_tables_name<S: StringOrBuilder>(&mut self, name: S) -> &mut Self {
+        self.set_name(NameElement::ListOfTables, name)
+    }
+
+    /// Sets the name of a document element, such as `\figurename`, in the preamble.
+    ///
+    /// # Parameters
+    /// - `element`: The element to rename.
+    /// - `value`: The new name.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, NameElement};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.set_name(NameElement::Chapter, "Rozdział");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \renewcommand{\chaptername}{Rozdział}
+    /// ```
+    pub fn set_name<S: StringOrBuilder>(&mut self, element: NameElement, value: S) -> &mut Self {
+        let value = value.merge_into(self);
+        self.push_preamble(&format!(
+            "\\renewcommand{{\\{}}}{{{}}}\n",
+            element,
+            value
+        ));
+        self
+    }
+
+    /// Adds a `\linebreak` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.line_break();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \linebreak
+    /// ```
+    pub fn line_break(&mut self) -> &mut Self {
+        self.content.push_str("\\linebreak\n");
+        self
+    }
+
+    /// Adds a `\pagebreak` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.page_break();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \pagebreak
+    /// ```
+    pub fn page_break(&mut self) -> &mut Self {
+        self.content.push_str("\\pagebreak\n");
+        self
+    }
+
+    /// Adds a `\noindent` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.no_indent();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \noindent
+    /// ```
+    pub fn no_indent(&mut self) -> &mut Self {
+        self.content.push_str("\\noindent\n");
+        self
+    }
+
+    /// Adds a `\centering` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.centering();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \centering
+    /// ```
+    pub fn centering(&mut self) -> &mut Self {
+        self.content.push_str("\\centering\n");
+        self
+    }
+
+    /// Adds an item to an itemized list in the document.
+    ///
+    /// # Parameters
+    /// - `content`: The content of the item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.itemize("Item 1");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \item Item 1
+    /// ```
+    pub fn itemize<S: StringOrBuilder>(&mut self, content: S) -> &mut Self {
+        let content = content.merge_into(self);
+        self.content
+            .push_str(&format!("\\item {}\n", content));
+        self
+    }
+
+    /// Adds an item with a custom label to a list in the document.
+    ///
+    /// This is mostly used in `description` lists.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the item.
+    /// - `content`: The content of the item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.item_optional("Term", "Definition");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \item[Term] Definition
+    /// ```
+    pub fn item_optional<S: StringOrBuilder, V: StringOrBuilder>(&mut self, label: S, content: V) -> &mut Self {
+        let label = label.merge_into(self);
+        let content = content.merge_into(self);
+        self.content.push_str(&format!(
+            "\\item[{}] {}\n",
+            label,
+            content
+        ));
+        self
+    }
+
+    /// Adds a bulleted list to the document.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the items of the list.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.bullet_list(|list| {
+    ///     list.item("First");
+    ///     list.item("Second");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{itemize}
+    /// \item First
+    /// \item Second
+    /// \end{itemize}
+    /// ```
+    pub fn bullet_list<F: FnOnce(&mut ListBuilder)>(&mut self, f: F) -> &mut Self {
+        self.list(Environment::Itemize, f);
+        self
+    }
+
+    /// Adds a numbered list to the document.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the items of the list.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.numbered_list(|list| {
+    ///     list.item("First");
+    ///     list.item("Second");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{enumerate}
+    /// \item First
+    /// \item Second
+    /// \end{enumerate}
+    /// ```
+    pub fn numbered_list<F: FnOnce(&mut ListBuilder)>(&mut self, f: F) -> &mut Self {
+        self.list(Environment::Enumerate(&EnumerateParams::new(None::<&str>)), f);
+        self
+    }
+
+    fn list<F: FnOnce(&mut ListBuilder)>(&mut self, env: Environment, f: F) -> &mut Self {
+        self.content
+            .push_str(&format!("\\begin{{{}}}\n", env.to_string()));
+        f(&mut ListBuilder { builder: self });
+        self.content
+            .push_str(&format!("\\end{{{}}}\n", env.to_string()));
+        self
+    }
+
+    /// Adds a row of cells to a tabular-like environment.
+    ///
+    /// The cells are joined with ` & ` and the row is terminated with `\\`.
+    /// An empty row still emits the terminating `\\`.
+    ///
+    /// # Parameters
+    /// - `cells`: The cells of the row.
+    ///
     /// # Example
     /// ```rust
-    /// use rusttex::{ContentBuilder, ColorModel};
+    /// use rusttex::{ContentBuilder, Environment, TabularParams};
     ///
+    /// let params = TabularParams::new("c|c", None::<&str>);
     /// let mut builder = ContentBuilder::new();
-    /// builder.text_color("Colored Text", "red", Some(ColorModel::RGB));
+    /// builder.env(Environment::Tabular(&params), |b: &mut ContentBuilder| {
+    ///     b.add_row(["Name", "Value"]);
+    ///     b.hline();
+    ///     b.add_row(["x", "1"]);
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \textcolor[RGB]{red}{Colored Text}
+    /// \begin{tabular}{c|c}
+    /// Name & Value \\
+    /// \hline
+    /// x & 1 \\
+    /// \end{tabular}
     /// ```
-    pub fn text_color<S: StringOrBuilder, V: StringOrBuilder>(&mut self, text: S, color: V, color_model: Option<ColorModel>) {
-        let color_model_str = match color_model {
-            Some(model) => format!("[{}]", model.to_string()),
+    pub fn add_row<I, S>(&mut self, cells: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: StringOrBuilder,
+    {
+        let row = cells
+            .into_iter()
+            .map(|c| c.merge_into(self))
+            .collect::<Vec<String>>()
+            .join(" & ");
+        self.content.push_str(&format!("{} \\\\\n", row));
+        self
+    }
+
+    /// Adds a complete `tabular` environment from rows of cells.
+    ///
+    /// If a header is given, it is written first and followed by `\hline`.
+    ///
+    /// # Parameters
+    /// - `params`: The parameters of the `tabular` environment.
+    /// - `rows`: The data rows.
+    /// - `header`: An optional header row.
+    ///
+    /// # Errors
+    /// Returns `LatexError::ColumnCountMismatch` if a row does not have the same number of cells
+    /// as the header, or as the first row if there is no header. Nothing is written in that case.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, TabularParams};
+    ///
+    /// let params = TabularParams::new("l|r", None::<&str>);
+    /// let mut builder = ContentBuilder::new();
+    /// builder
+    ///     .table_from_rows(&params, vec![vec!["x", "1"], vec!["y", "2"]], Some(vec!["Name", "Value"]))
+    ///     .unwrap();
+    /// assert!(builder.table_from_rows(&params, vec![vec!["x", "1"], vec!["y"]], None).is_err());
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \begin{tabular}{l|r}
+    /// Name & Value \\
+    /// \hline
+    /// x & 1 \\
+    /// y & 2 \\
+    /// \end{tabular}
+    /// ```
+    pub fn table_from_rows<S: StringOrBuilder>(
+        &mut self,
+        params: &TabularParams,
+        rows: Vec<Vec<S>>,
+        header: Option<Vec<S>>,
+    ) -> Result<&mut Self, LatexError> {
+        let expected = header
+            .as_ref()
+            .or(rows.first())
+            .map_or(0, |row| row.len());
+        if let Some(row) = rows.iter().find(|row| row.len() != expected) {
+            return Err(LatexError::ColumnCountMismatch {
+                expected,
+                found: row.len(),
+            });
+        }
+        let pos = params
+            .pos
+            .as_ref()
+            .map_or(String::new(), |p| format!("[{}]", p));
+        self.content
+            .push_str(&format!("\\begin{{tabular}}{}{{{}}}\n", pos, params.cols));
+        if let Some(header) = header {
+            self.add_row(header);
+            self.hline();
+        }
+        for row in rows {
+            self.add_row(row);
+        }
+        self.content.push_str("\\end{tabular}\n");
+        Ok(self)
+    }
+
+    /// Adds a `\hline` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.hline();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \hline
+    /// ```
+    pub fn hline(&mut self) -> &mut Self {
+        self.content.push_str("\\hline\n");
+        self
+    }
+
+    /// Adds a `\hrule` command to the document.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.hrule();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \hrule
+    /// ```
+    pub fn hrule(&mut self) -> &mut Self {
+        self.content.push_str("\\hrule\n");
+        self
+    }
+
+    /// Adds a rule (a filled rectangle) to the document.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the rule.
+    /// - `height`: The height of the rule.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{ContentBuilder, Length};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.rule(Length::linewidth(1.0), Length::pt(0.4));
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \rule{1\linewidth}{0.4pt}
+    /// ```
+    pub fn rule<S: StringOrBuilder, V: StringOrBuilder>(&mut self, width: S, height: V) -> &mut Self {
+        self.rule_raised(None::<&str>, width, height)
+    }
+
+    /// Adds a rule (a filled rectangle) raised by the given amount to the document.
+    ///
+    /// # Parameters
+    /// - `raise`: An optional amount to raise the rule by; negative values lower it.
+    /// - `width`: The width of the rule.
+    /// - `height`: The height of the rule.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.rule_raised(Some("0.5ex"), "3cm", "1pt");
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \rule[0.5ex]{3cm}{1pt}
+    /// ```
+    pub fn rule_raised<R: StringOrBuilder, S: StringOrBuilder, V: StringOrBuilder>(
+        &mut self,
+        raise: Option<R>,
+        width: S,
+        height: V,
+    ) -> &mut Self {
+        let width = width.merge_into(self);
+        let height = height.merge_into(self);
+        let raise_str = match raise {
+            Some(raise) => format!("[{}]", raise.merge_into(self)),
             None => String::new(),
         };
         self.content.push_str(&format!(
-            "\\textcolor{}{{{}}}{{{}}}",
-            color_model_str,
-            color.merge_str(),
-            text.merge_str()
+            "\\rule{}{{{}}}{{{}}}\n",
+            raise_str,
+            width,
+            height
         ));
+        self
     }
 
-    /// Adds horizontal space to the document.
-    ///
-    /// # Parameters
-    /// - `length`: The length of the space.
+    /// Adds a `\pause` command to a Beamer frame.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.hspace("1cm");
+    /// builder.pause();
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \hspace{1cm}
+    /// \pause
     /// ```
-    pub fn hspace<S: StringOrBuilder>(&mut self, length: S) {
-        self.content.push_str(&format!("\\hspace{{{}}}", length.merge_str()));
+    pub fn pause(&mut self) -> &mut Self {
+        self.content.push_str("\\pause\n");
+        self
     }
 
-    /// Adds vertical space to the document.
+    /// Adds content shown only on the given Beamer overlays.
     ///
     /// # Parameters
-    /// - `length`: The length of the space.
+    /// - `spec`: The overlay specification, with or without angle brackets (e.g. `2-` or `<2->`).
+    /// - `f`: A closure adding the content.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.vspace("1cm");
+    /// builder.only_fn("2-", |b| {
+    ///     b.add_literal("Revealed on the second slide.");
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \vspace{1cm}
+    /// \only<2->{Revealed on the second slide.}
     /// ```
-    pub fn vspace<S: StringOrBuilder>(&mut self, length: S) {
-        self.content.push_str(&format!("\\vspace{{{}}}", length.merge_str()));
+    pub fn only_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, spec: &str, f: F) -> &mut Self {
+        self.overlay("only", spec, f)
     }
 
-    /// Includes another LaTeX file in the document.
+    /// Adds content uncovered only on the given Beamer overlays.
+    ///
+    /// Unlike `only_fn`, the content occupies space on all overlays.
     ///
     /// # Parameters
-    /// - `filename`: The name of the file to include.
+    /// - `spec`: The overlay specification, with or without angle brackets (e.g. `2-` or `<2->`).
+    /// - `f`: A closure adding the content.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.include("otherfile");
+    /// builder.uncover_fn("<3>", |b| {
+    ///     b.add_literal("Uncovered on the third slide.");
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \include{otherfile}
+    /// \uncover<3>{Uncovered on the third slide.}
     /// ```
-    pub fn include<S: StringOrBuilder>(&mut self, filename: S) {
-        self.content
-            .push_str(&format!("\\include{{{}}}\n", filename.merge_str()));
+    pub fn uncover_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, spec: &str, f: F) -> &mut Self {
+        self.overlay("uncover", spec, f)
     }
 
-    /// Inputs another LaTeX file in the document.
+    /// Adds a Beamer `block` with a title.
+    ///
+    /// Requires the `beamer` document class.
     ///
     /// # Parameters
-    /// - `filename`: The name of the file to input.
+    /// - `title`: The title of the block.
+    /// - `f`: A closure adding the content of the block.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.input("otherfile");
+    /// builder.block_fn("Definition", |b| {
+    ///     b.add_literal("A prime has exactly two divisors.\n");
+    /// });
+    /// assert!(builder.build_document().ends_with("divisors.\n\\end{block}\n"));
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \input{otherfile}
+    /// \begin{block}{Definition}
+    /// A prime has exactly two divisors.
+    /// \end{block}
     /// ```
-    pub fn input<S: StringOrBuilder>(&mut self, filename: S) {
-        self.content
-            .push_str(&format!("\\input{{{}}}\n", filename.merge_str()));
+    pub fn block_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, title: &str, f: F) -> &mut Self {
+        self.beamer_block("block", title, f)
     }
 
-    /// Adds a `\clearpage` command to the document.
+    /// Adds a Beamer `alertblock` with a title.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the block.
+    /// - `f`: A closure adding the content of the block.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.clear_page();
+    /// builder.alert_block_fn("Warning", |b| {
+    ///     b.add_literal("Division by zero is undefined.");
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \clearpage
+    /// \begin{alertblock}{Warning}
+    /// Division by zero is undefined.
+    /// \end{alertblock}
     /// ```
-    pub fn clear_page(&mut self) {
-        self.content.push_str("\\clearpage\n");
+    pub fn alert_block_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, title: &str, f: F) -> &mut Self {
+        self.beamer_block("alertblock", title, f)
     }
 
-    /// Adds a `\newpage` command to the document.
+    /// Adds a Beamer `exampleblock` with a title.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the block.
+    /// - `f`: A closure adding the content of the block.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.new_page();
+    /// builder.example_block_fn("Example", |b| {
+    ///     b.add_literal("7 is a prime.");
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \newpage
+    /// \begin{exampleblock}{Example}
+    /// 7 is a prime.
+    /// \end{exampleblock}
     /// ```
-    pub fn new_page(&mut self) {
-        self.content.push_str("\\newpage\n");
+    pub fn example_block_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, title: &str, f: F) -> &mut Self {
+        self.beamer_block("exampleblock", title, f)
     }
 
-    /// Adds a `\linebreak` command to the document.
+    /// Adds a Beamer `columns` environment for side-by-side content.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the columns, usually with `column_fn`.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::{ContentBuilder, Length};
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.line_break();
+    /// builder.columns_fn(|b| {
+    ///     b.column_fn(Length::textwidth(0.5), |b| {
+    ///         b.add_literal("Left");
+    ///     });
+    ///     b.column_fn("0.5\\textwidth", |b| {
+    ///         b.add_literal("Right");
+    ///     });
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \linebreak
+    /// \begin{columns}
+    /// \begin{column}{0.5\textwidth}
+    /// Left
+    /// \end{column}
+    /// \begin{column}{0.5\textwidth}
+    /// Right
+    /// \end{column}
+    /// \end{columns}
     /// ```
-    pub fn line_break(&mut self) {
-        self.content.push_str("\\linebreak\n");
+    pub fn columns_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.content.push_str("\\begin{columns}\n");
+        f(self);
+        if !self.content.ends_with('\n') {
+            self.content.push('\n');
+        }
+        self.content.push_str("\\end{columns}\n");
+        self
     }
 
-    /// Adds a `\pagebreak` command to the document.
+    /// Adds a Beamer `column` of the given width, for use inside `columns_fn`.
+    ///
+    /// Requires the `beamer` document class.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the column.
+    /// - `f`: A closure adding the content of the column.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.page_break();
+    /// builder.column_fn("0.4\\textwidth", |b| {
+    ///     b.add_literal("Text\n");
+    /// });
+    /// assert!(builder.build_document().ends_with("Text\n\\end{column}\n"));
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \pagebreak
+    /// \begin{column}{0.4\textwidth}
+    /// Text
+    /// \end{column}
     /// ```
-    pub fn page_break(&mut self) {
-        self.content.push_str("\\pagebreak\n");
+    pub fn column_fn<S: StringOrBuilder, F: FnOnce(&mut ContentBuilder)>(&mut self, width: S, f: F) -> &mut Self {
+        let width = width.merge_into(self);
+        self.content
+            .push_str(&format!("\\begin{{column}}{{{}}}\n", width));
+        f(self);
+        if !self.content.ends_with('\n') {
+            self.content.push('\n');
+        }
+        self.content.push_str("\\end{column}\n");
+        self
     }
 
-    /// Adds a `\noindent` command to the document.
+    /// Adds a `framed` environment, which surrounds its content with a frame.
+    ///
+    /// Unlike `\fbox`, the environment can break across pages.
+    /// The `framed` package is added to the preamble if it is not already present.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content of the environment.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.no_indent();
+    /// builder.framed_fn(|b| {
+    ///     b.add_literal("Important note.");
+    /// });
+    /// assert!(builder.build_document().starts_with("\\usepackage{framed}\n"));
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \noindent
+    /// \usepackage{framed}
+    /// \begin{framed}
+    /// Important note.
+    /// \end{framed}
     /// ```
-    pub fn no_indent(&mut self) {
-        self.content.push_str("\\noindent\n");
+    pub fn framed_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.framed_env("framed", f)
     }
 
-    /// Adds a `\centering` command to the document.
+    /// Adds a `shaded` environment, which surrounds its content with a shaded background.
+    ///
+    /// Unlike `\fbox`, the environment can break across pages.
+    /// The `framed` package is added to the preamble if it is not already present.
+    /// Requires a color named `shadecolor`, e.g. defined with `define_color`.
+    ///
+    /// # Parameters
+    /// - `f`: A closure adding the content of the environment.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.centering();
+    /// builder.shaded_fn(|b| {
+    ///     b.add_literal("Important note.");
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \centering
+    /// \usepackage{framed}
+    /// \begin{shaded}
+    /// Important note.
+    /// \end{shaded}
     /// ```
-    pub fn centering(&mut self) {
-        self.content.push_str("\\centering\n");
+    pub fn shaded_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.framed_env("shaded", f)
     }
 
-    /// Adds an item to an itemized list in the document.
+    /// Adds a `leftbar` environment, which surrounds its content with a vertical bar on the left, e.g. for block quotes.
+    ///
+    /// Unlike `\fbox`, the environment can break across pages.
+    /// The `framed` package is added to the preamble if it is not already present.
     ///
     /// # Parameters
-    /// - `content`: The content of the item.
+    /// - `f`: A closure adding the content of the environment.
     ///
     /// # Example
     /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
     /// let mut builder = ContentBuilder::new();
-    /// builder.itemize("Item 1");
+    /// builder.leftbar_fn(|b| {
+    ///     b.add_literal("Important note.");
+    /// });
     /// ```
     ///
     /// **Generated LaTeX:**
     /// ```latex
-    /// \item {Item 1}
+    /// \usepackage{framed}
+    /// \begin{leftbar}
+    /// Important note.
+    /// \end{leftbar}
     /// ```
-    pub fn itemize<S: StringOrBuilder>(&mut self, content: S) {
+    pub fn leftbar_fn<F: FnOnce(&mut ContentBuilder)>(&mut self, f: F) -> &mut Self {
+        self.framed_env("leftbar", f)
+    }
+
+    fn framed_env<F: FnOnce(&mut ContentBuilder)>(&mut self, name: &str, f: F) -> &mut Self {
+        self.ensure_package("framed");
+        self.content.push_str(&format!("\\begin{{{}}}\n", name));
+        f(self);
+        if !self.content.ends_with('\n') {
+            self.content.push('\n');
+        }
+        self.content.push_str(&format!("\\end{{{}}}\n", name));
+        self
+    }
+
+    fn beamer_block<F: FnOnce(&mut ContentBuilder)>(&mut self, name: &str, title: &str, f: F) -> &mut Self {
+        self.content
+            .push_str(&format!("\\begin{{{}}}{{{}}}\n", name, title));
+        f(self);
+        if !self.content.ends_with('\n') {
+            self.content.push('\n');
+        }
+        self.content.push_str(&format!("\\end{{{}}}\n", name));
+        self
+    }
+
+    fn overlay<F: FnOnce(&mut ContentBuilder)>(&mut self, command: &str, spec: &str, f: F) -> &mut Self {
         self.content
-            .push_str(&format!("\\item {{{}}}\n", content.merge_str()));
+            .push_str(&format!("\\{}{}{{", command, utils::overlay_spec(spec)));
+        f(self);
+        self.content.push_str("}\n");
+        self
     }
 
     /// Adds an environment to the document.
     ///
+    /// The packages required by `Environment::Align` and `Environment::AlignStar` (`amsmath`),
+    /// `Environment::MultiCols` (`multicol`), `Environment::SubFigure` (`subcaption`) and an
+    /// `Environment::Enumerate` label format (`enumitem`) are added to the preamble if they are
+    /// not already present.
+    ///
     /// # Parameters
     /// - `env`: The environment to add.
     /// - `content`: The content of the environment.
@@ -943,14 +5799,19 @@ impl ContentBuilder {
     /// This is an abstract.
     /// \end{abstract}
     /// ```
-    pub fn env<S: StringOrBuilder>(&mut self, env: Environment, content: S) {
+    pub fn env<S: StringOrBuilder>(&mut self, env: Environment, content: S) -> &mut Self {
+        let content = content.merge_into(self);
+        if matches!(env, Environment::Align | Environment::AlignStar) {
+            self.ensure_package("amsmath");
+        }
         match env {
             Environment::Abstract
+            | Environment::Align
+            | Environment::AlignStar
             | Environment::Center
             | Environment::Description
             | Environment::DisplayMath
             | Environment::Document
-            | Environment::Enumerate
             | Environment::EqnArray
             | Environment::Equation
             | Environment::FlushLeft
@@ -960,14 +5821,13 @@ impl ContentBuilder {
             | Environment::Quotation
             | Environment::Quote
             | Environment::Tabbing
-            | Environment::Theorem
             | Environment::TitlePage
             | Environment::TrivList
             | Environment::Verbatim
             | Environment::Verse => {
                 self.content
                     .push_str(&format!("\\begin{{{}}}\n", env.to_string()));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -982,7 +5842,7 @@ impl ContentBuilder {
                     pos,
                     params.cols
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -992,7 +5852,7 @@ impl ContentBuilder {
                     env.to_string(),
                     &params.placement
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -1007,7 +5867,7 @@ impl ContentBuilder {
                     options,
                     &params.filename,
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -1018,32 +5878,24 @@ impl ContentBuilder {
                     &params.labeling,
                     &params.spacing,
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
             Environment::Minipage(params) => {
-                let position = params
-                    .position
-                    .as_ref()
-                    .map_or(String::from("[]"), |p| format!("[{}]", p.merge_str()));
-                let height = params
-                    .height
-                    .as_ref()
-                    .map_or(String::from("[]"), |h| format!("[{}]", h.merge_str()));
-                let inner_pos = params
-                    .inner_pos
-                    .as_ref()
-                    .map_or(String::from("[]"), |i| format!("[{}]", i.merge_str()));
+                // `MinipageParams::new` rejects skipped optionals, so the given ones are leading.
+                let optionals_str = [&params.position, &params.height, &params.inner_pos]
+                    .into_iter()
+                    .flatten()
+                    .map(|o| format!("[{}]", o))
+                    .collect::<String>();
                 self.content.push_str(&format!(
-                    "\\begin{{{}}}{}{}{}{{{}}}\n",
+                    "\\begin{{{}}}{}{{{}}}\n",
                     env.to_string(),
-                    position,
-                    height,
-                    inner_pos,
+                    optionals_str,
                     &params.width
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -1060,7 +5912,7 @@ impl ContentBuilder {
                     size,
                     offset
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -1071,7 +5923,7 @@ impl ContentBuilder {
                     .map_or(String::new(), |p| format!("[{}]", p.merge_str()));
                 self.content
                     .push_str(&format!("\\begin{{{}}}{}\n", env.to_string(), placement));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -1086,7 +5938,7 @@ impl ContentBuilder {
                     pos,
                     params.cols
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
@@ -1096,10 +5948,133 @@ impl ContentBuilder {
                     env.to_string(),
                     &params.widest_label,
                 ));
-                self.content.push_str(&format!("{}\n", content.merge_str()));
+                self.content.push_str(&format!("{}\n", content));
+                self.content
+                    .push_str(&format!("\\end{{{}}}\n", env.to_string()));
+            }
+            Environment::Theorem(params) => {
+                let title = params
+                    .title
+                    .as_ref()
+                    .map_or(String::new(), |t| format!("[{}]", t));
+                self.content
+                    .push_str(&format!("\\begin{{{}}}{}\n", env.to_string(), title));
+                self.content.push_str(&format!("{}\n", content));
+                self.content
+                    .push_str(&format!("\\end{{{}}}\n", env.to_string()));
+            }
+            Environment::Enumerate(params) => {
+                let label = match &params.label_format {
+                    Some(format) => {
+                        self.ensure_package("enumitem");
+                        format!("[label={}]", format)
+                    }
+                    None => String::new(),
+                };
+                self.content
+                    .push_str(&format!("\\begin{{{}}}{}\n", env.to_string(), label));
+                self.content.push_str(&format!("{}\n", content));
+                self.content
+                    .push_str(&format!("\\end{{{}}}\n", env.to_string()));
+            }
+            Environment::SubFigure(params) => {
+                self.ensure_package("subcaption");
+                self.content.push_str(&format!(
+                    "\\begin{{{}}}{{{}}}\n",
+                    env.to_string(),
+                    params.width
+                ));
+                self.content.push_str(&format!("{}\n", content));
+                self.content
+                    .push_str(&format!("\\end{{{}}}\n", env.to_string()));
+            }
+            Environment::MultiCols(params) => {
+                self.ensure_package("multicol");
+                self.content.push_str(&format!(
+                    "\\begin{{{}}}{{{}}}\n",
+                    env.to_string(),
+                    params.num_columns
+                ));
+                self.content.push_str(&format!("{}\n", content));
                 self.content
                     .push_str(&format!("\\end{{{}}}\n", env.to_string()));
             }
         }
+        self
+    }
+}
+
+/// A builder for the items of a list, writing into the parent `ContentBuilder`.
+///
+/// # Example
+/// ```rust
+/// use rusttex::ContentBuilder;
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.bullet_list(|list| {
+///     list.item("Plain item");
+///     list.item_with_label("Term", "Definition");
+/// });
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \begin{itemize}
+/// \item Plain item
+/// \item[Term] Definition
+/// \end{itemize}
+/// ```
+pub struct ListBuilder<'a> {
+    builder: &'a mut ContentBuilder,
+}
+
+impl<'a> ListBuilder<'a> {
+    /// Adds an item to the list.
+    ///
+    /// # Parameters
+    /// - `content`: The content of the item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.numbered_list(|list| {
+    ///     list.item("Item 1");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \item Item 1
+    /// ```
+    pub fn item<S: StringOrBuilder>(&mut self, content: S) -> &mut Self {
+        self.builder.itemize(content);
+        self
+    }
+
+    /// Adds an item with a custom label to the list.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the item.
+    /// - `content`: The content of the item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::ContentBuilder;
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.bullet_list(|list| {
+    ///     list.item_with_label("Term", "Definition");
+    /// });
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \item[Term] Definition
+    /// ```
+    pub fn item_with_label<S: StringOrBuilder, V: StringOrBuilder>(&mut self, label: S, content: V) -> &mut Self {
+        self.builder.item_optional(label, content);
+        self
     }
 }
@@ -1,4 +1,5 @@
-use crate::StringOrBuilder;
+use crate::{utils, ContentBuilder, LatexError, StringOrBuilder};
+use std::fmt;
 
 /// Represents the document class for a LaTeX document.
 ///
@@ -82,6 +83,12 @@ pub enum DocumentClassOptions {
     OpenRight,
     /// Represents the `openany` option for document classes in LaTeX.
     OpenAny,
+    /// Represents the `10pt` font size option for document classes in LaTeX.
+    Pt10,
+    /// Represents the `11pt` font size option for document classes in LaTeX.
+    Pt11,
+    /// Represents the `12pt` font size option for document classes in LaTeX.
+    Pt12,
     /// Represents a custom option for document classes in LaTeX.
     Custom(String),
 }
@@ -109,7 +116,9 @@ pub enum ColorModel {
     /// Represents the `RGB` color model in LaTeX.
     RGBFull,
     /// Represents the `named` color model in LaTeX.
-    Named
+    Named,
+    /// Represents the `HTML` color model in LaTeX, a six-digit hexadecimal value.
+    HTML,
 }
 
 impl ToString for ColorModel {
@@ -120,10 +129,985 @@ impl ToString for ColorModel {
             ColorModel::RGB => String::from("rgb"),
             ColorModel::RGBFull => String::from("RGB"),
             ColorModel::Named => String::from("named"),
+            ColorModel::HTML => String::from("HTML"),
         }
     }
 }
 
+/// Represents a color usable with color-aware commands such as `text_color`.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{Color, ColorModel, ContentBuilder};
+///
+/// let brand = Color::custom("brand", ColorModel::RGB, "0.2,0.3,0.8").unwrap();
+/// let mut builder = ContentBuilder::new();
+/// builder.text_color("Brand Text", brand, None).unwrap();
+/// builder.text_color("Red Text", Color::Red, None).unwrap();
+/// builder.text_color("Teal Text", Color::Named(String::from("teal")), None).unwrap();
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \definecolor{brand}{rgb}{0.2,0.3,0.8}
+/// \textcolor{brand}{Brand Text}\textcolor{red}{Red Text}\textcolor{teal}{Teal Text}
+/// ```
+pub enum Color {
+    /// Represents the `red` color of the `xcolor` package.
+    Red,
+    /// Represents the `green` color of the `xcolor` package.
+    Green,
+    /// Represents the `blue` color of the `xcolor` package.
+    Blue,
+    /// Represents the `cyan` color of the `xcolor` package.
+    Cyan,
+    /// Represents the `magenta` color of the `xcolor` package.
+    Magenta,
+    /// Represents the `yellow` color of the `xcolor` package.
+    Yellow,
+    /// Represents the `black` color of the `xcolor` package.
+    Black,
+    /// Represents the `white` color of the `xcolor` package.
+    White,
+    /// Represents the `gray` color of the `xcolor` package.
+    Gray,
+    /// Represents the `darkgray` color of the `xcolor` package.
+    DarkGray,
+    /// Represents the `lightgray` color of the `xcolor` package.
+    LightGray,
+    /// Represents the `brown` color of the `xcolor` package.
+    Brown,
+    /// Represents the `lime` color of the `xcolor` package.
+    Lime,
+    /// Represents the `olive` color of the `xcolor` package.
+    Olive,
+    /// Represents the `orange` color of the `xcolor` package.
+    Orange,
+    /// Represents the `pink` color of the `xcolor` package.
+    Pink,
+    /// Represents the `purple` color of the `xcolor` package.
+    Purple,
+    /// Represents the `teal` color of the `xcolor` package.
+    Teal,
+    /// Represents the `violet` color of the `xcolor` package.
+    Violet,
+    /// Represents a color referred to by its name, e.g. `red`.
+    Named(String),
+    /// Represents a color given by its red, green and blue components in the `RGB` model.
+    Rgb(u8, u8, u8),
+    /// Represents a color given by a six-digit hexadecimal value in the `HTML` model.
+    Hex(String),
+    /// Represents a custom color, declared with `\definecolor` in the preamble when first used.
+    Custom {
+        /// The name of the color.
+        name: String,
+        /// The color model of the specification.
+        model: ColorModel,
+        /// The specification of the color in the given model.
+        spec: String,
+    },
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Color::Red => write!(f, "red"),
+            Color::Green => write!(f, "green"),
+            Color::Blue => write!(f, "blue"),
+            Color::Cyan => write!(f, "cyan"),
+            Color::Magenta => write!(f, "magenta"),
+            Color::Yellow => write!(f, "yellow"),
+            Color::Black => write!(f, "black"),
+            Color::White => write!(f, "white"),
+            Color::Gray => write!(f, "gray"),
+            Color::DarkGray => write!(f, "darkgray"),
+            Color::LightGray => write!(f, "lightgray"),
+            Color::Brown => write!(f, "brown"),
+            Color::Lime => write!(f, "lime"),
+            Color::Olive => write!(f, "olive"),
+            Color::Orange => write!(f, "orange"),
+            Color::Pink => write!(f, "pink"),
+            Color::Purple => write!(f, "purple"),
+            Color::Teal => write!(f, "teal"),
+            Color::Violet => write!(f, "violet"),
+            Color::Named(name) => write!(f, "{}", name),
+            Color::Rgb(r, g, b) => write!(f, "{},{},{}", r, g, b),
+            Color::Hex(hex) => write!(f, "{}", hex),
+            Color::Custom { name, .. } => write!(f, "{}", name),
+        }
+    }
+}
+
+impl Color {
+    /// Creates a color from its red, green and blue components.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{Color, ContentBuilder};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.text_color_typed("Brand Text", Color::rgb(51, 76, 204)).unwrap();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \textcolor[RGB]{51,76,204}{Brand Text}
+    /// ```
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color::Rgb(r, g, b)
+    }
+
+    /// Creates a color from a hexadecimal value such as `#334CCC` or `334ccc`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{Color, ContentBuilder};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// builder.text_color_typed("Brand Text", Color::hex("#334ccc")).unwrap();
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \textcolor[HTML]{334CCC}{Brand Text}
+    /// ```
+    pub fn hex(hex: &str) -> Self {
+        Color::Hex(hex.trim().trim_start_matches('#').to_uppercase())
+    }
+
+    /// Creates a custom color, declared in the preamble of the document it is used in.
+    ///
+    /// # Errors
+    /// Returns `LatexError::InvalidColorSpec` if `spec` does not match `model`, as for `define_color`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::{Color, ColorModel, ContentBuilder};
+    ///
+    /// let mut builder = ContentBuilder::new();
+    /// let brand = Color::custom("brand", ColorModel::RGB, "0.2,0.3,0.8")?;
+    /// builder.text_color_typed("Brand Text", brand)?;
+    /// assert!(Color::custom("brand", ColorModel::RGB, "51,76,204").is_err());
+    /// # Ok::<(), rusttex::LatexError>(())
+    /// ```
+    ///
+    /// **Generated LaTeX:**
+    /// ```latex
+    /// \definecolor{brand}{rgb}{0.2,0.3,0.8}
+    /// \textcolor{brand}{Brand Text}
+    /// ```
+    pub fn custom(name: &str, model: ColorModel, spec: &str) -> Result<Self, LatexError> {
+        if !utils::is_valid_color_spec(&model, spec) {
+            return Err(LatexError::InvalidColorSpec {
+                model: model.to_string(),
+                spec: spec.to_string(),
+            });
+        }
+        Ok(Color::Custom {
+            name: name.to_string(),
+            model,
+            spec: spec.to_string(),
+        })
+    }
+
+    /// Returns the color model needed to interpret the color, or `None` for named colors.
+    pub fn model(&self) -> Option<ColorModel> {
+        match self {
+            Color::Rgb(..) => Some(ColorModel::RGBFull),
+            Color::Hex(_) => Some(ColorModel::HTML),
+            _ => None,
+        }
+    }
+
+    /// Starts a color mix expression with the given percentage of this color.
+    ///
+    /// Only named colors, including custom colors, can be mixed.
+    ///
+    /// # Errors
+    /// Returns `LatexError::UnnamedColorMix` for `Color::Rgb` and `Color::Hex`, which have no name
+    /// to refer to in the expression. Declare them with `Color::custom` or `define_color` instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::Color;
+    ///
+    /// let expr = Color::Blue.mix(30)?.with(Color::White)?;
+    /// assert_eq!(expr.to_string(), "blue!30!white");
+    /// assert!(Color::rgb(0, 128, 255).mix(30).is_err());
+    /// # Ok::<(), rusttex::LatexError>(())
+    /// ```
+    pub fn mix(self, percent: u8) -> Result<ColorExpr, LatexError> {
+        self.check_mixable()?;
+        Ok(ColorExpr {
+            expr: format!("{}!{}", self, percent),
+            definitions: self.definition().into_iter().collect(),
+        })
+    }
+
+    /// Fails for colors given by a specification instead of a name, which `xcolor` cannot mix.
+    fn check_mixable(&self) -> Result<(), LatexError> {
+        match self.model() {
+            Some(_) => Err(LatexError::UnnamedColorMix(self.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the name and `\definecolor` declaration of a custom color.
+    fn definition(&self) -> Option<(String, String)> {
+        match self {
+            Color::Custom { name, model, spec } => Some((
+                name.clone(),
+                format!("\\definecolor{{{}}}{{{}}}{{{}}}\n", name, model.to_string(), spec),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// A builder for `xcolor` mix expressions such as `blue!30!white`.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{Color, ContentBuilder};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.color_let("tint", Color::Blue.mix(30)?);
+/// builder.color_let("shade", Color::Red.mix(60)?.with(Color::Black)?);
+/// # Ok::<(), rusttex::LatexError>(())
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \colorlet{tint}{blue!30}
+/// \colorlet{shade}{red!60!black}
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorExpr {
+    expr: String,
+    definitions: Vec<(String, String)>,
+}
+
+impl ColorExpr {
+    /// Mixes the expression so far with the given color.
+    ///
+    /// # Errors
+    /// Returns `LatexError::UnnamedColorMix` for `Color::Rgb` and `Color::Hex`, as `Color::mix` does.
+    pub fn with(mut self, color: Color) -> Result<Self, LatexError> {
+        color.check_mixable()?;
+        self.expr.push_str(&format!("!{}", color));
+        self.definitions.extend(color.definition());
+        Ok(self)
+    }
+
+    /// Takes the given percentage of the expression so far, for mixing with a further color.
+    pub fn mix(mut self, percent: u8) -> Self {
+        self.expr.push_str(&format!("!{}", percent));
+        self
+    }
+}
+
+impl fmt::Display for ColorExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
+
+impl StringOrBuilder for ColorExpr {
+    fn merge_str(self) -> String {
+        self.expr
+    }
+
+    fn merge_into(self, builder: &mut ContentBuilder) -> String {
+        for (name, declaration) in &self.definitions {
+            builder.ensure_color(name, declaration);
+        }
+        self.expr
+    }
+}
+
+impl StringOrBuilder for Color {
+    fn merge_str(self) -> String {
+        match self {
+            Color::Named(name) => name,
+            Color::Custom { name, .. } => name,
+            color => color.to_string(),
+        }
+    }
+
+    fn merge_into(self, builder: &mut ContentBuilder) -> String {
+        if let Some((name, declaration)) = self.definition() {
+            builder.ensure_color(&name, &declaration);
+        }
+        self.merge_str()
+    }
+
+    fn color_model(&self) -> Option<ColorModel> {
+        self.model()
+    }
+}
+
+/// Represents barcode symbologies supported by the `pst-barcode` package.
+///
+/// # Example
+/// ```rust
+/// use rusttex::BarcodeKind;
+///
+/// let kind = BarcodeKind::EAN13;
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \psbarcode{9780201529838}{}{ean13}
+/// ```
+pub enum BarcodeKind {
+    /// Represents the `ean13` barcode type.
+    EAN13,
+    /// Represents the `ean8` barcode type.
+    EAN8,
+    /// Represents the `upca` barcode type.
+    UPCA,
+    /// Represents the `code128` barcode type.
+    Code128,
+    /// Represents the `code39` barcode type.
+    Code39,
+    /// Represents a custom barcode type.
+    Custom(String),
+}
+
+impl fmt::Display for BarcodeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            BarcodeKind::EAN13 => write!(f, "ean13"),
+            BarcodeKind::EAN8 => write!(f, "ean8"),
+            BarcodeKind::UPCA => write!(f, "upca"),
+            BarcodeKind::Code128 => write!(f, "code128"),
+            BarcodeKind::Code39 => write!(f, "code39"),
+            BarcodeKind::Custom(custom) => write!(f, "{}", custom),
+        }
+    }
+}
+
+/// Represents a typed LaTeX length, usable wherever a dimension is accepted.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, Length};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.vspace(Length::cm(1.5));
+/// builder.hspace(Length::textwidth(0.5));
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \vspace{1.5cm}
+/// \hspace{0.5\textwidth}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    /// A length in centimeters.
+    Cm(f64),
+    /// A length in millimeters.
+    Mm(f64),
+    /// A length in inches.
+    In(f64),
+    /// A length in points.
+    Pt(f64),
+    /// A length relative to the font size (`em`).
+    Em(f64),
+    /// A length relative to the x-height of the font (`ex`).
+    Ex(f64),
+    /// A fraction of `\textwidth`.
+    TextWidth(f64),
+    /// A fraction of `\linewidth`.
+    LineWidth(f64),
+}
+
+impl Length {
+    /// Creates a length in centimeters.
+    pub fn cm(value: f64) -> Self {
+        Length::Cm(value)
+    }
+
+    /// Creates a length in millimeters.
+    pub fn mm(value: f64) -> Self {
+        Length::Mm(value)
+    }
+
+    /// Creates a length in inches.
+    pub fn inches(value: f64) -> Self {
+        Length::In(value)
+    }
+
+    /// Creates a length in points.
+    pub fn pt(value: f64) -> Self {
+        Length::Pt(value)
+    }
+
+    /// Creates a length in `em`.
+    pub fn em(value: f64) -> Self {
+        Length::Em(value)
+    }
+
+    /// Creates a length in `ex`.
+    pub fn ex(value: f64) -> Self {
+        Length::Ex(value)
+    }
+
+    /// Creates a fraction of `\textwidth`.
+    pub fn textwidth(fraction: f64) -> Self {
+        Length::TextWidth(fraction)
+    }
+
+    /// Creates a fraction of `\linewidth`.
+    pub fn linewidth(fraction: f64) -> Self {
+        Length::LineWidth(fraction)
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Length::Cm(value) => write!(f, "{}cm", value),
+            Length::Mm(value) => write!(f, "{}mm", value),
+            Length::In(value) => write!(f, "{}in", value),
+            Length::Pt(value) => write!(f, "{}pt", value),
+            Length::Em(value) => write!(f, "{}em", value),
+            Length::Ex(value) => write!(f, "{}ex", value),
+            Length::TextWidth(fraction) => write!(f, "{}\\textwidth", fraction),
+            Length::LineWidth(fraction) => write!(f, "{}\\linewidth", fraction),
+        }
+    }
+}
+
+impl StringOrBuilder for Length {
+    fn merge_str(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Represents content destined for math mode.
+///
+/// Unlike plain strings, `MathText` is never escaped as body text, since characters like
+/// `{`, `}`, `_` and `^` have their math-mode meaning.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, MathText};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.inline_math(MathText(String::from("x_{i}^2")));
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// $x_{i}^2$
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MathText(pub String);
+
+impl StringOrBuilder for MathText {
+    fn merge_str(self) -> String {
+        self.0
+    }
+
+    fn merge_escaped(self) -> String {
+        self.0
+    }
+}
+
+/// Represents the built-in Beamer presentation themes.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, BeamerTheme};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.use_theme(BeamerTheme::Madrid);
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \usetheme{Madrid}
+/// ```
+pub enum BeamerTheme {
+    /// Represents the `default` theme.
+    Default,
+    /// Represents the `AnnArbor` theme.
+    AnnArbor,
+    /// Represents the `Antibes` theme.
+    Antibes,
+    /// Represents the `Bergen` theme.
+    Bergen,
+    /// Represents the `Berkeley` theme.
+    Berkeley,
+    /// Represents the `Berlin` theme.
+    Berlin,
+    /// Represents the `Boadilla` theme.
+    Boadilla,
+    /// Represents the `CambridgeUS` theme.
+    CambridgeUS,
+    /// Represents the `Copenhagen` theme.
+    Copenhagen,
+    /// Represents the `Darmstadt` theme.
+    Darmstadt,
+    /// Represents the `Dresden` theme.
+    Dresden,
+    /// Represents the `Frankfurt` theme.
+    Frankfurt,
+    /// Represents the `Goettingen` theme.
+    Goettingen,
+    /// Represents the `Hannover` theme.
+    Hannover,
+    /// Represents the `Ilmenau` theme.
+    Ilmenau,
+    /// Represents the `JuanLesPins` theme.
+    JuanLesPins,
+    /// Represents the `Luebeck` theme.
+    Luebeck,
+    /// Represents the `Madrid` theme.
+    Madrid,
+    /// Represents the `Malmoe` theme.
+    Malmoe,
+    /// Represents the `Marburg` theme.
+    Marburg,
+    /// Represents the `Montpellier` theme.
+    Montpellier,
+    /// Represents the `PaloAlto` theme.
+    PaloAlto,
+    /// Represents the `Pittsburgh` theme.
+    Pittsburgh,
+    /// Represents the `Rochester` theme.
+    Rochester,
+    /// Represents the `Singapore` theme.
+    Singapore,
+    /// Represents the `Szeged` theme.
+    Szeged,
+    /// Represents the `Warsaw` theme.
+    Warsaw,
+    /// Represents a custom theme.
+    Custom(String),
+}
+
+impl fmt::Display for BeamerTheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            BeamerTheme::Default => write!(f, "default"),
+            BeamerTheme::AnnArbor => write!(f, "AnnArbor"),
+            BeamerTheme::Antibes => write!(f, "Antibes"),
+            BeamerTheme::Bergen => write!(f, "Bergen"),
+            BeamerTheme::Berkeley => write!(f, "Berkeley"),
+            BeamerTheme::Berlin => write!(f, "Berlin"),
+            BeamerTheme::Boadilla => write!(f, "Boadilla"),
+            BeamerTheme::CambridgeUS => write!(f, "CambridgeUS"),
+            BeamerTheme::Copenhagen => write!(f, "Copenhagen"),
+            BeamerTheme::Darmstadt => write!(f, "Darmstadt"),
+            BeamerTheme::Dresden => write!(f, "Dresden"),
+            BeamerTheme::Frankfurt => write!(f, "Frankfurt"),
+            BeamerTheme::Goettingen => write!(f, "Goettingen"),
+            BeamerTheme::Hannover => write!(f, "Hannover"),
+            BeamerTheme::Ilmenau => write!(f, "Ilmenau"),
+            BeamerTheme::JuanLesPins => write!(f, "JuanLesPins"),
+            BeamerTheme::Luebeck => write!(f, "Luebeck"),
+            BeamerTheme::Madrid => write!(f, "Madrid"),
+            BeamerTheme::Malmoe => write!(f, "Malmoe"),
+            BeamerTheme::Marburg => write!(f, "Marburg"),
+            BeamerTheme::Montpellier => write!(f, "Montpellier"),
+            BeamerTheme::PaloAlto => write!(f, "PaloAlto"),
+            BeamerTheme::Pittsburgh => write!(f, "Pittsburgh"),
+            BeamerTheme::Rochester => write!(f, "Rochester"),
+            BeamerTheme::Singapore => write!(f, "Singapore"),
+            BeamerTheme::Szeged => write!(f, "Szeged"),
+            BeamerTheme::Warsaw => write!(f, "Warsaw"),
+            BeamerTheme::Custom(custom) => write!(f, "{}", custom),
+        }
+    }
+}
+
+impl StringOrBuilder for BeamerTheme {
+    fn merge_str(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Represents the LaTeX font size declarations.
+///
+/// The variants are named after the declarations, e.g. `Normalsize` for `\normalsize`.
+/// Declarations that only differ in case use the suffix `Cap` for a capitalized first letter
+/// (`\Large`, `\Huge`) and `Upper` for an all-uppercase name (`\LARGE`).
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, FontSize};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.font_size(FontSize::Large).add_literal("Big").font_size(FontSize::LargeUpper).add_literal("Bigger");
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \large
+/// Big\LARGE
+/// Bigger
+/// ```
+pub enum FontSize {
+    /// Represents the `\tiny` font size.
+    Tiny,
+    /// Represents the `\scriptsize` font size.
+    Scriptsize,
+    /// Represents the `\footnotesize` font size.
+    Footnotesize,
+    /// Represents the `\small` font size.
+    Small,
+    /// Represents the `\normalsize` font size.
+    Normalsize,
+    /// Represents the `\large` font size.
+    Large,
+    /// Represents the `\Large` font size, one step larger than `Large`.
+    LargeCap,
+    /// Represents the `\LARGE` font size, one step larger than `LargeCap`.
+    LargeUpper,
+    /// Represents the `\huge` font size.
+    Huge,
+    /// Represents the `\Huge` font size, one step larger than `Huge`.
+    HugeCap,
+}
+
+impl fmt::Display for FontSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            FontSize::Tiny => write!(f, "tiny"),
+            FontSize::Scriptsize => write!(f, "scriptsize"),
+            FontSize::Footnotesize => write!(f, "footnotesize"),
+            FontSize::Small => write!(f, "small"),
+            FontSize::Normalsize => write!(f, "normalsize"),
+            FontSize::Large => write!(f, "large"),
+            FontSize::LargeCap => write!(f, "Large"),
+            FontSize::LargeUpper => write!(f, "LARGE"),
+            FontSize::Huge => write!(f, "huge"),
+            FontSize::HugeCap => write!(f, "Huge"),
+        }
+    }
+}
+
+/// Represents the sectioning levels of table of contents entries.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, TocLevel};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.add_contents_line("toc", TocLevel::Section, "Preface");
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \addcontentsline{toc}{section}{Preface}
+/// ```
+pub enum TocLevel {
+    /// Represents the `part` level.
+    Part,
+    /// Represents the `chapter` level.
+    Chapter,
+    /// Represents the `section` level.
+    Section,
+    /// Represents the `subsection` level.
+    Subsection,
+    /// Represents the `subsubsection` level.
+    Subsubsection,
+    /// Represents a custom level.
+    Custom(String),
+}
+
+impl fmt::Display for TocLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            TocLevel::Part => write!(f, "part"),
+            TocLevel::Chapter => write!(f, "chapter"),
+            TocLevel::Section => write!(f, "section"),
+            TocLevel::Subsection => write!(f, "subsection"),
+            TocLevel::Subsubsection => write!(f, "subsubsection"),
+            TocLevel::Custom(custom) => write!(f, "{}", custom),
+        }
+    }
+}
+
+impl StringOrBuilder for TocLevel {
+    fn merge_str(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Represents LaTeX counters.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, Counter};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.set_counter(Counter::Page, 1);
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \setcounter{page}{1}
+/// ```
+pub enum Counter {
+    /// Represents the `page` counter.
+    Page,
+    /// Represents the `part` counter.
+    Part,
+    /// Represents the `chapter` counter.
+    Chapter,
+    /// Represents the `section` counter.
+    Section,
+    /// Represents the `subsection` counter.
+    Subsection,
+    /// Represents the `figure` counter.
+    Figure,
+    /// Represents the `table` counter.
+    Table,
+    /// Represents the `footnote` counter.
+    Footnote,
+    /// Represents the `equation` counter.
+    Equation,
+    /// Represents a custom counter.
+    Custom(String),
+}
+
+impl fmt::Display for Counter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Counter::Page => write!(f, "page"),
+            Counter::Part => write!(f, "part"),
+            Counter::Chapter => write!(f, "chapter"),
+            Counter::Section => write!(f, "section"),
+            Counter::Subsection => write!(f, "subsection"),
+            Counter::Figure => write!(f, "figure"),
+            Counter::Table => write!(f, "table"),
+            Counter::Footnote => write!(f, "footnote"),
+            Counter::Equation => write!(f, "equation"),
+            Counter::Custom(custom) => write!(f, "{}", custom),
+        }
+    }
+}
+
+impl StringOrBuilder for Counter {
+    fn merge_str(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Represents LaTeX page styles.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, PageStyle};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.page_style(PageStyle::Headings);
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \pagestyle{headings}
+/// ```
+pub enum PageStyle {
+    /// Represents the `empty` page style.
+    Empty,
+    /// Represents the `plain` page style.
+    Plain,
+    /// Represents the `headings` page style.
+    Headings,
+    /// Represents the `myheadings` page style.
+    MyHeadings,
+    /// Represents a custom page style.
+    Custom(String),
+}
+
+impl fmt::Display for PageStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            PageStyle::Empty => write!(f, "empty"),
+            PageStyle::Plain => write!(f, "plain"),
+            PageStyle::Headings => write!(f, "headings"),
+            PageStyle::MyHeadings => write!(f, "myheadings"),
+            PageStyle::Custom(custom) => write!(f, "{}", custom),
+        }
+    }
+}
+
+/// Represents paragraph layout presets.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, ParagraphStyle};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.paragraph_style(ParagraphStyle::Blocked);
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \setlength{\parindent}{0pt}
+/// \setlength{\parskip}{1em}
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ParagraphStyle {
+    /// Indented paragraphs without extra space between them, as in the standard classes.
+    #[default]
+    Indented,
+    /// Unindented paragraphs separated by vertical space.
+    Blocked,
+}
+
+/// Represents the customizable names of document elements, such as `\figurename`.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ContentBuilder, NameElement};
+///
+/// let mut builder = ContentBuilder::new();
+/// builder.set_name(NameElement::Figure, "Fig.");
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \renewcommand{\figurename}{Fig.}
+/// ```
+pub enum NameElement {
+    /// Represents the `\chaptername` name.
+    Chapter,
+    /// Represents the `\figurename` name.
+    Figure,
+    /// Represents the `\tablename` name.
+    Table,
+    /// Represents the `\abstractname` name.
+    Abstract,
+    /// Represents the `\bibname` name.
+    Bib,
+    /// Represents the `\refname` name.
+    Ref,
+    /// Represents the `\appendixname` name.
+    Appendix,
+    /// Represents the `\partname` name.
+    Part,
+    /// Represents the `\indexname` name.
+    Index,
+    /// Represents the `\contentsname` name.
+    Contents,
+    /// Represents the `\listfigurename` name.
+    ListOfFigures,
+    /// Represents the `\listtablename` name.
+    ListOfTables,
+}
+
+impl fmt::Display for NameElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            NameElement::Chapter => write!(f, "chaptername"),
+            NameElement::Figure => write!(f, "figurename"),
+            NameElement::Table => write!(f, "tablename"),
+            NameElement::Abstract => write!(f, "abstractname"),
+            NameElement::Bib => write!(f, "bibname"),
+            NameElement::Ref => write!(f, "refname"),
+            NameElement::Appendix => write!(f, "appendixname"),
+            NameElement::Part => write!(f, "partname"),
+            NameElement::Index => write!(f, "indexname"),
+            NameElement::Contents => write!(f, "contentsname"),
+            NameElement::ListOfFigures => write!(f, "listfigurename"),
+            NameElement::ListOfTables => write!(f, "listtablename"),
+        }
+    }
+}
+
+/// A builder for `tabular` and `array` column specifications.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{ColumnSpec, TabularParams};
+///
+/// let spec = ColumnSpec::new().vline().center().vline().center().vline();
+/// let params = TabularParams::new(spec, None::<&str>);
+/// assert_eq!(params.cols, "|c|c|");
+///
+/// let spec = ColumnSpec::new().left().repeat(3, "r");
+/// assert_eq!(spec.to_string(), "l*{3}{r}");
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \begin{tabular}{|c|c|}
+/// ...
+/// \end{tabular}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnSpec {
+    spec: String,
+}
+
+impl ColumnSpec {
+    /// Creates a new, empty `ColumnSpec` instance.
+    pub fn new() -> Self {
+        ColumnSpec { spec: String::new() }
+    }
+
+    /// Adds a left-aligned column (`l`).
+    pub fn left(mut self) -> Self {
+        self.spec.push('l');
+        self
+    }
+
+    /// Adds a centered column (`c`).
+    pub fn center(mut self) -> Self {
+        self.spec.push('c');
+        self
+    }
+
+    /// Adds a right-aligned column (`r`).
+    pub fn right(mut self) -> Self {
+        self.spec.push('r');
+        self
+    }
+
+    /// Adds a vertical line (`|`).
+    pub fn vline(mut self) -> Self {
+        self.spec.push('|');
+        self
+    }
+
+    /// Adds a paragraph column of the given width (`p{width}`).
+    ///
+    /// # Parameters
+    /// - `width`: The width of the column.
+    pub fn paragraph<S: StringOrBuilder>(mut self, width: S) -> Self {
+        self.spec.push_str(&format!("p{{{}}}", width.merge_str()));
+        self
+    }
+
+    /// Adds `n` repetitions of a column specification (`*{n}{col}`).
+    ///
+    /// # Parameters
+    /// - `n`: The number of repetitions.
+    /// - `col`: The column specification to repeat.
+    pub fn repeat<S: StringOrBuilder>(mut self, n: u32, col: S) -> Self {
+        self.spec.push_str(&format!("*{{{}}}{{{}}}", n, col.merge_str()));
+        self
+    }
+}
+
+impl fmt::Display for ColumnSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.spec)
+    }
+}
+
+impl StringOrBuilder for ColumnSpec {
+    fn merge_str(self) -> String {
+        self.spec
+    }
+}
+
 /// Parameters for the LaTeX `array` environment.
 ///
 /// # Example
@@ -165,6 +1149,90 @@ impl ArrayParams {
     }
 }
 
+/// A builder for float placement specifiers, as used by `FigureParams` and `TableParams`.
+///
+/// The specifiers are always written in the canonical order `!htbp`, regardless of the
+/// order in which they were added.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{FigureParams, Placement};
+///
+/// let placement = Placement::new().here().top().force();
+/// assert_eq!(placement.to_string(), "!ht");
+///
+/// let params = FigureParams::new(placement);
+/// assert_eq!(params.placement, "!ht");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Placement {
+    here: bool,
+    top: bool,
+    bottom: bool,
+    page: bool,
+    force: bool,
+}
+
+impl Placement {
+    /// Creates a new, empty `Placement` instance.
+    pub fn new() -> Self {
+        Placement::default()
+    }
+
+    /// Allows placing the float here, at the position in the text (`h`).
+    pub fn here(mut self) -> Self {
+        self.here = true;
+        self
+    }
+
+    /// Allows placing the float at the top of a page (`t`).
+    pub fn top(mut self) -> Self {
+        self.top = true;
+        self
+    }
+
+    /// Allows placing the float at the bottom of a page (`b`).
+    pub fn bottom(mut self) -> Self {
+        self.bottom = true;
+        self
+    }
+
+    /// Allows placing the float on a separate page of floats (`p`).
+    pub fn page(mut self) -> Self {
+        self.page = true;
+        self
+    }
+
+    /// Overrides the internal parameters LaTeX uses to decide on float placement (`!`).
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+}
+
+impl fmt::Display for Placement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let placement = [
+            (self.force, '!'),
+            (self.here, 'h'),
+            (self.top, 't'),
+            (self.bottom, 'b'),
+            (self.page, 'p'),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, c)| c)
+        .collect::<String>();
+        write!(f, "{}", placement)
+    }
+}
+
+impl StringOrBuilder for Placement {
+    fn merge_str(self) -> String {
+        self.to_string()
+    }
+}
+
 /// Parameters for the LaTeX `figure` environment.
 ///
 /// # Example
@@ -326,16 +1394,18 @@ impl ListParams {
 
 /// Parameters for the LaTeX `minipage` environment.
 ///
+/// Trailing optional arguments that are `None` are omitted.
+///
 /// # Example
 /// ```rust
-/// use rusttex::{MinipageParams, StringOrBuilder};
+/// use rusttex::MinipageParams;
 ///
-/// let params = MinipageParams::new(Some("c"), Some("2cm"), None, "5cm");
+/// let params = MinipageParams::new(Some("c"), Some("2cm"), None::<&str>, "5cm").unwrap();
 /// ```
 ///
 /// **Generated LaTeX:**
 /// ```latex
-/// \begin{minipage}[c][2cm][]{5cm}
+/// \begin{minipage}[c][2cm]{5cm}
 /// ...
 /// \end{minipage}
 /// ```
@@ -359,22 +1429,37 @@ impl MinipageParams {
     /// - `inner_pos`: Optional inner position.
     /// - `width`: Width of the minipage.
     ///
+    /// # Errors
+    /// Returns `LatexError::SkippedOptionalArgument` if `position` is `None` while `height` is
+    /// given, or if `height` is `None` while `inner_pos` is given, since LaTeX's optional
+    /// arguments are positional.
+    ///
     /// # Example
     /// ```rust
-    /// let params = MinipageParams::new(Some("c"), Some("2cm"), None, "5cm");
+    /// use rusttex::MinipageParams;
+    ///
+    /// let params = MinipageParams::new(Some("c"), Some("2cm"), None::<&str>, "5cm").unwrap();
+    /// assert!(MinipageParams::new(None::<&str>, Some("2cm"), None::<&str>, "5cm").is_err());
+    /// assert!(MinipageParams::new(Some("t"), None::<&str>, Some("b"), "5cm").is_err());
     /// ```
     pub fn new<S: StringOrBuilder, V: StringOrBuilder, T: StringOrBuilder, U: StringOrBuilder>(
         position: Option<S>,
         height: Option<V>,
         inner_pos: Option<T>,
         width: U,
-    ) -> Self {
-        MinipageParams {
+    ) -> Result<Self, LatexError> {
+        if position.is_none() && (height.is_some() || inner_pos.is_some()) {
+            return Err(LatexError::SkippedOptionalArgument(String::from("position")));
+        }
+        if height.is_none() && inner_pos.is_some() {
+            return Err(LatexError::SkippedOptionalArgument(String::from("height")));
+        }
+        Ok(MinipageParams {
             position: position.map(|p| p.merge_str()),
             height: height.map(|h| h.merge_str()),
             inner_pos: inner_pos.map(|i| i.merge_str()),
             width: width.merge_str(),
-        }
+        })
     }
 }
 
@@ -537,6 +1622,219 @@ impl TheBubliographyParams {
     }
 }
 
+/// Represents an entry of a bibliography rendered with `add_bibliography`.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{BibEntry, ContentBuilder};
+///
+/// let mut entry = BibEntry::new("knuth1984", vec![String::from("D. Knuth")], "The TeXbook", Some(1984));
+/// entry.publisher = Some(String::from("Addison-Wesley"));
+/// let mut builder = ContentBuilder::new();
+/// builder.add_bibliography(&[entry], "9");
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \begin{thebibliography}{9}
+/// \bibitem{knuth1984} D. Knuth, The TeXbook, Addison-Wesley, 1984.
+/// \end{thebibliography}
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BibEntry {
+    /// The citation key of the entry.
+    pub key: String,
+    /// The authors of the work.
+    pub authors: Vec<String>,
+    /// The title of the work.
+    pub title: String,
+    /// The publisher or journal of the work.
+    pub publisher: Option<String>,
+    /// The year of publication.
+    pub year: Option<u32>,
+}
+
+impl BibEntry {
+    /// Creates a new `BibEntry` instance.
+    ///
+    /// # Parameters
+    /// - `key`: The citation key.
+    /// - `authors`: The authors of the work.
+    /// - `title`: The title of the work.
+    /// - `year`: Optional year of publication.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::BibEntry;
+    ///
+    /// let entry = BibEntry::new("doe2020", vec![String::from("J. Doe")], "A Study", Some(2020));
+    /// ```
+    pub fn new<S: StringOrBuilder, V: StringOrBuilder>(key: S, authors: Vec<String>, title: V, year: Option<u32>) -> Self {
+        BibEntry {
+            key: key.merge_str(),
+            authors,
+            title: title.merge_str(),
+            publisher: None,
+            year,
+        }
+    }
+}
+
+/// Parameters for the LaTeX `enumerate` environment.
+///
+/// # Example
+/// ```rust
+/// use rusttex::EnumerateParams;
+///
+/// let params = EnumerateParams::new(Some("(\\alph*)"));
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \usepackage{enumitem}
+/// \begin{enumerate}[label=(\alph*)]
+/// ...
+/// \end{enumerate}
+/// ```
+pub struct EnumerateParams {
+    /// Specifies the optional label format, e.g. `(\alph*)`. Requires the `enumitem` package.
+    pub label_format: Option<String>,
+}
+
+impl EnumerateParams {
+    /// Creates a new `EnumerateParams` instance.
+    ///
+    /// # Parameters
+    /// - `label_format`: Optional label format.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::EnumerateParams;
+    ///
+    /// let params = EnumerateParams::new(None::<&str>);
+    /// ```
+    pub fn new<S: StringOrBuilder>(label_format: Option<S>) -> Self {
+        EnumerateParams {
+            label_format: label_format.map(|l| l.merge_str()),
+        }
+    }
+}
+
+/// Parameters for the `subfigure` environment of the `subcaption` package.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{Length, SubFigureParams};
+///
+/// let params = SubFigureParams::new(Length::textwidth(0.45));
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \begin{subfigure}{0.45\textwidth}
+/// ...
+/// \end{subfigure}
+/// ```
+pub struct SubFigureParams {
+    /// Specifies the width of the subfigure.
+    pub width: String,
+}
+
+impl SubFigureParams {
+    /// Creates a new `SubFigureParams` instance.
+    ///
+    /// # Parameters
+    /// - `width`: Width of the subfigure.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::SubFigureParams;
+    ///
+    /// let params = SubFigureParams::new("0.45\\textwidth");
+    /// ```
+    pub fn new<S: StringOrBuilder>(width: S) -> Self {
+        SubFigureParams {
+            width: width.merge_str(),
+        }
+    }
+}
+
+/// Parameters for the LaTeX `theorem` environment.
+///
+/// # Example
+/// ```rust
+/// use rusttex::TheoremParams;
+///
+/// let params = TheoremParams::new(Some("Pythagoras"));
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \begin{theorem}[Pythagoras]
+/// ...
+/// \end{theorem}
+/// ```
+pub struct TheoremParams {
+    /// Specifies the optional title of the theorem.
+    pub title: Option<String>,
+}
+
+impl TheoremParams {
+    /// Creates a new `TheoremParams` instance.
+    ///
+    /// # Parameters
+    /// - `title`: Optional title.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::TheoremParams;
+    ///
+    /// let params = TheoremParams::new(Some("Pythagoras"));
+    /// ```
+    pub fn new<S: StringOrBuilder>(title: Option<S>) -> Self {
+        TheoremParams {
+            title: title.map(|t| t.merge_str()),
+        }
+    }
+}
+
+/// Parameters for the `multicols` environment of the `multicol` package.
+///
+/// # Example
+/// ```rust
+/// use rusttex::MultiColsParams;
+///
+/// let params = MultiColsParams::new(3);
+/// ```
+///
+/// **Generated LaTeX:**
+/// ```latex
+/// \begin{multicols}{3}
+/// ...
+/// \end{multicols}
+/// ```
+pub struct MultiColsParams {
+    /// Specifies the number of columns.
+    pub num_columns: u32,
+}
+
+impl MultiColsParams {
+    /// Creates a new `MultiColsParams` instance.
+    ///
+    /// # Parameters
+    /// - `num_columns`: The number of columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rusttex::MultiColsParams;
+    ///
+    /// let params = MultiColsParams::new(2);
+    /// ```
+    pub fn new(num_columns: u32) -> Self {
+        MultiColsParams { num_columns }
+    }
+}
+
 /// Represents LaTeX environments.
 ///
 /// # Example
@@ -555,6 +1853,10 @@ impl TheBubliographyParams {
 pub enum Environment<'a> {
     /// Represents the `abstract` environment in LaTeX.
     Abstract,
+    /// Represents the `align` environment of the `amsmath` package.
+    Align,
+    /// Represents the `align*` environment of the `amsmath` package.
+    AlignStar,
     /// Represents the `array` environment in LaTeX.
     Array(&'a ArrayParams),
     /// Represents the `center` environment in LaTeX.
@@ -566,7 +1868,7 @@ pub enum Environment<'a> {
     /// Represents the `document` environment in LaTeX.
     Document,
     /// Represents the `enumerate` environment in LaTeX.
-    Enumerate,
+    Enumerate(&'a EnumerateParams),
     /// Represents the `eqnarray` environment in LaTeX.
     EqnArray,
     /// Represents the `equation` environment in LaTeX.
@@ -587,12 +1889,16 @@ pub enum Environment<'a> {
     Math,
     /// Represents the `minipage` environment in LaTeX.
     Minipage(&'a MinipageParams),
+    /// Represents the `multicols` environment of the `multicol` package.
+    MultiCols(&'a MultiColsParams),
     /// Represents the `picture` environment in LaTeX.
     Picture(&'a PictureParams),
     /// Represents the `quotation` environment in LaTeX.
     Quotation,
     /// Represents the `quote` environment in LaTeX.
     Quote,
+    /// Represents the `subfigure` environment of the `subcaption` package.
+    SubFigure(&'a SubFigureParams),
     /// Represents the `tabbing` environment in LaTeX.
     Tabbing,
     /// Represents the `table` environment in LaTeX.
@@ -602,7 +1908,7 @@ pub enum Environment<'a> {
     /// Represents the `thebibliography` environment in LaTeX.
     TheBibliography(&'a TheBubliographyParams),
     /// Represents the `theorem` environment in LaTeX.
-    Theorem,
+    Theorem(&'a TheoremParams),
     /// Represents the `titlepage` environment in LaTeX.
     TitlePage,
     /// Represents the `trivlist` environment in LaTeX.
@@ -633,12 +1939,14 @@ impl<'a> ToString for Environment<'a> {
     fn to_string(&self) -> String {
         match &self {
             Environment::Abstract => String::from("abstract"),
+            Environment::Align => String::from("align"),
+            Environment::AlignStar => String::from("align*"),
             Environment::Array(_) => String::from("array"),
             Environment::Center => String::from("center"),
             Environment::Description => String::from("description"),
             Environment::DisplayMath => String::from("displaymath"),
             Environment::Document => String::from("document"),
-            Environment::Enumerate => String::from("enumerate"),
+            Environment::Enumerate(_) => String::from("enumerate"),
             Environment::EqnArray => String::from("eqnarray"),
             Environment::Equation => String::from("equation"),
             Environment::Figure(_) => String::from("figure"),
@@ -649,14 +1957,16 @@ impl<'a> ToString for Environment<'a> {
             Environment::List(_) => String::from("list"),
             Environment::Math => String::from("math"),
             Environment::Minipage(_) => String::from("minipage"),
+            Environment::MultiCols(_) => String::from("multicols"),
             Environment::Picture(_) => String::from("picture"),
             Environment::Quotation => String::from("quotation"),
             Environment::Quote => String::from("quote"),
+            Environment::SubFigure(_) => String::from("subfigure"),
             Environment::Tabbing => String::from("tabbing"),
             Environment::Table(_) => String::from("table"),
             Environment::Tabular(_) => String::from("tabular"),
             Environment::TheBibliography(_) => String::from("thebibliography"),
-            Environment::Theorem => String::from("theorem"),
+            Environment::Theorem(_) => String::from("theorem"),
             Environment::TitlePage => String::from("titlepage"),
             Environment::TrivList => String::from("trivlist"),
             Environment::Verbatim => String::from("verbatim"),
@@ -701,6 +2011,9 @@ impl ToString for DocumentClassOptions {
             DocumentClassOptions::TwoSide => String::from("twoside"),
             DocumentClassOptions::OpenRight => String::from("openright"),
             DocumentClassOptions::OpenAny => String::from("openany"),
+            DocumentClassOptions::Pt10 => String::from("10pt"),
+            DocumentClassOptions::Pt11 => String::from("11pt"),
+            DocumentClassOptions::Pt12 => String::from("12pt"),
             DocumentClassOptions::Custom(custom) => custom.clone(),
         }
     }
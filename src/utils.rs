@@ -1,9 +1,237 @@
+use crate::ColorModel;
+
 #[macro_export]
 /// A macro to easily mix Strings and enums as options
+///
+/// Besides plain expressions, `key = value` entries are accepted and rendered as `key=value`.
+///
+/// # Example
+/// ```rust
+/// use rusttex::{DocumentClassOptions, options};
+///
+/// let options = options![DocumentClassOptions::A4Paper, margin = "1in", "twoside"];
+/// let rendered = options.iter().map(|o| o.to_string()).collect::<Vec<String>>().join(",");
+/// assert_eq!(rendered, "a4paper,margin=1in,twoside");
+/// ```
 macro_rules! options {
-    ($($item:expr),*) => {
-        vec![
-            $(Box::new($item) as Box<dyn ToString>),*
-        ]
+    (@acc [$($out:expr),*]) => {
+        vec![$($out),*]
     };
+    (@acc [$($out:expr),*] $key:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::options!(
+            @acc [$($out,)* Box::new(format!("{}={}", stringify!($key), $value)) as Box<dyn ToString>]
+            $($($rest)*)?
+        )
+    };
+    (@acc [$($out:expr),*] $item:expr $(, $($rest:tt)*)?) => {
+        $crate::options!(
+            @acc [$($out,)* Box::new($item) as Box<dyn ToString>]
+            $($($rest)*)?
+        )
+    };
+    ($($item:tt)*) => {
+        $crate::options!(@acc [] $($item)*)
+    };
+}
+
+/// Checks whether a command name consists only of letters and `@`.
+pub(crate) fn is_valid_command_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic() || c == '@')
+}
+
+/// Checks whether a color specification has the number of components required by its model,
+/// each within the range of that model.
+pub(crate) fn is_valid_color_spec(model: &ColorModel, spec: &str) -> bool {
+    let components = match model {
+        ColorModel::CMYK => 4,
+        ColorModel::Gray => 1,
+        ColorModel::RGB | ColorModel::RGBFull => 3,
+        ColorModel::Named => return !spec.trim().is_empty(),
+        ColorModel::HTML => return spec.len() == 6 && spec.chars().all(|c| c.is_ascii_hexdigit()),
+    };
+    let parts = spec.split(',').map(str::trim).collect::<Vec<&str>>();
+    parts.len() == components
+        && parts.iter().all(|p| match model {
+            ColorModel::RGBFull => p.parse::<u8>().is_ok(),
+            _ => p.parse::<f64>().is_ok_and(|v| (0.0..=1.0).contains(&v)),
+        })
+}
+
+/// Represents a run of words in a word-level diff.
+pub(crate) enum WordDiff<'a> {
+    /// Words present in both texts.
+    Same(Vec<&'a str>),
+    /// Words only present in the old text.
+    Deleted(Vec<&'a str>),
+    /// Words only present in the new text.
+    Added(Vec<&'a str>),
+}
+
+/// Computes a word-level diff of two texts using the longest common subsequence.
+pub(crate) fn word_diff<'a>(old: &'a str, new: &'a str) -> Vec<WordDiff<'a>> {
+    let old = old.split_whitespace().collect::<Vec<&str>>();
+    let new = new.split_whitespace().collect::<Vec<&str>>();
+
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut runs: Vec<WordDiff> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            match runs.last_mut() {
+                Some(WordDiff::Same(words)) => words.push(old[i]),
+                _ => runs.push(WordDiff::Same(vec![old[i]])),
+            }
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            match runs.last_mut() {
+                Some(WordDiff::Deleted(words)) => words.push(old[i]),
+                _ => runs.push(WordDiff::Deleted(vec![old[i]])),
+            }
+            i += 1;
+        } else {
+            match runs.last_mut() {
+                Some(WordDiff::Added(words)) => words.push(new[j]),
+                _ => runs.push(WordDiff::Added(vec![new[j]])),
+            }
+            j += 1;
+        }
+    }
+    runs
+}
+
+/// Escapes a URL for use in `\href` and `\url`.
+///
+/// Only `%` and `#` are escaped; characters like `_`, `~` and `&` are handled by `hyperref`
+/// itself and are left untouched, unlike in body text.
+///
+/// # Example
+/// ```rust
+/// use rusttex::utils::escape_url;
+///
+/// assert_eq!(escape_url("https://example.com/a_b?q=50%#top"), "https://example.com/a_b?q=50\\%\\#top");
+/// ```
+pub fn escape_url(url: &str) -> String {
+    url.replace('%', "\\%").replace('#', "\\#")
+}
+
+/// Checks whether a text contains a paragraph break, i.e. a blank line.
+///
+/// A paragraph break inside the argument of most commands (such as `\footnote` or `\section`)
+/// is a LaTeX error, so text passed to those commands can be validated with this function first.
+///
+/// # Example
+/// ```rust
+/// use rusttex::utils::has_paragraph_break;
+///
+/// assert!(has_paragraph_break("First paragraph.\n\nSecond paragraph."));
+/// assert!(has_paragraph_break("First paragraph.\n  \nSecond paragraph."));
+/// assert!(!has_paragraph_break("A single\nparagraph.\n"));
+/// ```
+pub fn has_paragraph_break(text: &str) -> bool {
+    let lines = text.split('\n').collect::<Vec<&str>>();
+    lines.len() > 2 && lines[1..lines.len() - 1].iter().any(|line| line.trim().is_empty())
+}
+
+/// Prefixes every `\\` line break with `\protect`, unless already protected, so that the text
+/// can be used in moving arguments such as section titles and captions.
+pub(crate) fn protect_line_breaks(text: &str) -> String {
+    let mut protected = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek() == Some(&'\\') {
+            chars.next();
+            if !protected.ends_with("\\protect") {
+                protected.push_str("\\protect");
+            }
+            protected.push_str("\\\\");
+        } else {
+            protected.push(c);
+        }
+    }
+    protected
+}
+
+/// Wraps a Beamer overlay specification in angle brackets, accepting it with or without them.
+pub(crate) fn overlay_spec(spec: &str) -> String {
+    format!("<{}>", spec.trim().trim_start_matches('<').trim_end_matches('>'))
+}
+
+/// Escapes LaTeX special characters for use in body text.
+///
+/// # Example
+/// ```rust
+/// use rusttex::utils::escape_latex;
+///
+/// assert_eq!(escape_latex("50% of R&D_costs"), "50\\% of R\\&D\\_costs");
+/// assert_eq!(escape_latex("a~b^c\\d"), "a\\textasciitilde{}b\\textasciicircum{}c\\textbackslash{}d");
+/// ```
+pub fn escape_latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Counts the words of LaTeX source, ignoring commands, environment names, braces and comments.
+pub(crate) fn approx_word_count(source: &str) -> usize {
+    let mut text = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if !next.is_ascii_alphabetic() {
+                        break;
+                    }
+                    name.push(next);
+                    chars.next();
+                }
+                if name.is_empty() {
+                    chars.next();
+                } else if (name == "begin" || name == "end") && chars.peek() == Some(&'{') {
+                    for next in chars.by_ref() {
+                        if next == '}' {
+                            break;
+                        }
+                    }
+                }
+                text.push(' ');
+            }
+            '%' => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+                text.push(' ');
+            }
+            '{' | '}' => {}
+            _ => text.push(c),
+        }
+    }
+    text.split_whitespace()
+        .filter(|word| word.chars().any(|c| c.is_alphanumeric()))
+        .count()
 }